@@ -1,9 +1,11 @@
 use std::{fmt::Debug, ops::RangeInclusive, sync::Arc};
 
 use egui::{
-    Pos2, Rangef, Rect, Response, Sense, TextStyle, TextWrapMode, Ui, Vec2, WidgetText,
+    Galley, Pos2, Rangef, Rect, Response, Sense, Stroke, TextStyle, TextWrapMode, Ui, Vec2,
+    WidgetText,
     emath::{Rot2, remap_clamp},
     epaint::TextShape,
+    pos2, vec2,
 };
 
 use super::{GridMark, transform::PlotTransform};
@@ -11,8 +13,23 @@ use super::{GridMark, transform::PlotTransform};
 // Gap between tick labels and axis label in units of the axis label height
 const AXIS_LABEL_GAP: f32 = 0.25;
 
+/// Grid marks closer together than this (in points) get no tick marks.
+const MIN_MINOR_TICK_SPACING: f32 = 4.0;
+
 pub(super) type AxisFormatterFn<'a> = dyn Fn(GridMark, &RangeInclusive<f64>) -> String + 'a;
 
+type ConversionFn<'a> = dyn Fn(f64) -> f64 + 'a;
+
+/// Converts between plot values and the unit shown on a derived axis.
+#[derive(Clone)]
+pub(super) struct AxisConversion<'a> {
+    /// From plot values to the unit of the axis.
+    pub(super) forward: Arc<ConversionFn<'a>>,
+
+    /// From the unit of the axis back to plot values.
+    pub(super) inverse: Arc<ConversionFn<'a>>,
+}
+
 /// X or Y axis.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Axis {
@@ -107,6 +124,13 @@ pub struct AxisHints<'a> {
     pub(super) min_thickness: f32,
     pub(super) placement: Placement,
     pub(super) label_spacing: Rangef,
+    pub(super) secondary_y_axis: Option<String>,
+    pub(super) conversion: Option<AxisConversion<'a>>,
+    pub(super) tick_label_angle: f32,
+    pub(super) auto_tick_label_angle: Option<f32>,
+    pub(super) tick_label_wrap_width: Option<f32>,
+    pub(super) tick_marks: Option<TickMarks>,
+    pub(super) spine: bool,
 }
 
 impl<'a> AxisHints<'a> {
@@ -134,6 +158,13 @@ impl<'a> AxisHints<'a> {
                 Axis::X => Rangef::new(60.0, 80.0), // labels can get pretty wide
                 Axis::Y => Rangef::new(20.0, 30.0), // text isn't very high
             },
+            secondary_y_axis: None,
+            conversion: None,
+            tick_label_angle: 0.0,
+            auto_tick_label_angle: None,
+            tick_label_wrap_width: None,
+            tick_marks: None,
+            spine: false,
         }
     }
 
@@ -156,6 +187,94 @@ impl<'a> AxisHints<'a> {
         emath::format_with_decimals_in_range(mark.value, num_decimals..=num_decimals)
     }
 
+    /// Show the range of the named secondary y axis instead of the main y range.
+    ///
+    /// Items plotted against the same name (e.g. with [`crate::Line::secondary_y_axis`]) get
+    /// their own y bounds and auto-bounds. Dragging and zooming the plot moves all y axes together,
+    /// while dragging or zooming over this axis only changes this axis.
+    ///
+    /// Only has an effect on y axes.
+    #[inline]
+    pub fn secondary_y_axis(mut self, name: impl Into<String>) -> Self {
+        self.secondary_y_axis = Some(name.into());
+        self
+    }
+
+    /// Show the values in a different unit, e.g. Fahrenheit on a second axis next to Celsius.
+    ///
+    /// `forward` converts plot values to the unit of this axis, and `inverse` converts back.
+    /// The conversion must be monotonic, but doesn't have to be linear
+    /// (e.g. wavelength and frequency).
+    ///
+    /// Grid marks are picked in the converted unit, so the tick labels are round numbers
+    /// instead of converted versions of the main axis ticks.
+    /// The formatter receives the converted values.
+    pub fn conversion(
+        mut self,
+        forward: impl Fn(f64) -> f64 + 'a,
+        inverse: impl Fn(f64) -> f64 + 'a,
+    ) -> Self {
+        self.conversion = Some(AxisConversion {
+            forward: Arc::new(forward),
+            inverse: Arc::new(inverse),
+        });
+        self
+    }
+
+    /// Rotate the tick labels by this many radians clockwise,
+    /// e.g. `-std::f32::consts::FRAC_PI_4` for x axis labels that read upwards at 45°.
+    ///
+    /// Slanted labels need less room along the axis, so long labels can be closer together
+    /// before they are skipped. The axis grows to fit the rotated labels.
+    ///
+    /// Default: `0.0`.
+    #[inline]
+    pub fn tick_label_angle(mut self, angle: f32) -> Self {
+        self.tick_label_angle = angle;
+        self
+    }
+
+    /// Rotate the tick labels by `angle` (see [`Self::tick_label_angle`]), but only while
+    /// that makes room for labels that would otherwise collide with their neighbors.
+    #[inline]
+    pub fn auto_rotate_tick_labels(mut self, angle: f32) -> Self {
+        self.auto_tick_label_angle = Some(angle);
+        self
+    }
+
+    /// Wrap tick labels that are wider than `width` onto multiple lines.
+    ///
+    /// The formatter can also break labels into lines with `\n`.
+    #[inline]
+    pub fn tick_label_wrap_width(mut self, width: f32) -> Self {
+        self.tick_label_wrap_width = Some(width);
+        self
+    }
+
+    /// Draw tick marks at the grid marks of this axis.
+    ///
+    /// Grid marks that are far enough apart to be labeled (see [`Self::label_spacing`])
+    /// get major tick marks, and the next finer ones get minor tick marks.
+    /// Tick marks are shown even when the grid is hidden with [`crate::Plot::show_grid`].
+    #[inline]
+    pub fn tick_marks(mut self, tick_marks: TickMarks) -> Self {
+        self.tick_marks = Some(tick_marks);
+        self
+    }
+
+    /// Draw a line along the edge of the axis next to the plot.
+    ///
+    /// It uses the stroke of the [`Self::tick_marks`], if any.
+    /// The spine is part of the axis, so it is only drawn where the axis is shown
+    /// (see [`crate::Plot::show_axes`]). For a spine without tick labels, use a
+    /// [`Self::formatter`] that returns empty strings.
+    /// Default: `false`.
+    #[inline]
+    pub fn show_spine(mut self, show: bool) -> Self {
+        self.spine = show;
+        self
+    }
+
     /// Specify axis label.
     ///
     /// The default is 'x' for x-axes and 'y' for y-axes.
@@ -202,6 +321,103 @@ impl<'a> AxisHints<'a> {
     }
 }
 
+/// Which side of the axis line tick marks are drawn on, see [`TickMarks::placement`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum TickPlacement {
+    /// Pointing into the plot.
+    Inside,
+
+    /// Pointing away from the plot, towards the tick labels.
+    #[default]
+    Outside,
+
+    /// Crossing the axis line.
+    Both,
+}
+
+/// Tick marks along an axis, see [`AxisHints::tick_marks`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct TickMarks {
+    placement: TickPlacement,
+    major_length: f32,
+    minor_length: f32,
+    stroke: Option<Stroke>,
+}
+
+impl Default for TickMarks {
+    fn default() -> Self {
+        Self {
+            placement: TickPlacement::Outside,
+            major_length: 6.0,
+            minor_length: 3.0,
+            stroke: None,
+        }
+    }
+}
+
+impl TickMarks {
+    /// Which side of the axis line the tick marks are on. Default: [`TickPlacement::Outside`].
+    #[inline]
+    pub fn placement(mut self, placement: TickPlacement) -> Self {
+        self.placement = placement;
+        self
+    }
+
+    /// Length of the major tick marks, in points. Default: `6.0`.
+    #[inline]
+    pub fn length(mut self, length: f32) -> Self {
+        self.major_length = length;
+        self
+    }
+
+    /// Length of the minor tick marks, in points. Set it to zero to hide them. Default: `3.0`.
+    #[inline]
+    pub fn minor_length(mut self, length: f32) -> Self {
+        self.minor_length = length;
+        self
+    }
+
+    /// The stroke of the tick marks (and the spine).
+    /// Default: a thin line in the text color.
+    #[inline]
+    pub fn stroke(mut self, stroke: impl Into<Stroke>) -> Self {
+        self.stroke = Some(stroke.into());
+        self
+    }
+
+    /// The length of the tick mark of a grid mark `spacing_in_points` away from its neighbors:
+    /// major if it is far enough apart to be labeled, minor for the next finer ones,
+    /// and `None` if it is too close to them for a tick mark.
+    fn length_for_spacing(&self, spacing_in_points: f32, label_spacing: Rangef) -> Option<f32> {
+        let length = if label_spacing.min < spacing_in_points {
+            self.major_length
+        } else if MIN_MINOR_TICK_SPACING <= spacing_in_points {
+            self.minor_length
+        } else {
+            return None;
+        };
+        (0.0 < length).then_some(length)
+    }
+
+    /// Where a tick mark of `length` starts and ends, measured from the edge of the plot
+    /// towards the labels.
+    fn extent(&self, length: f32) -> (f32, f32) {
+        match self.placement {
+            TickPlacement::Inside => (-length, 0.0),
+            TickPlacement::Outside => (0.0, length),
+            TickPlacement::Both => (-length, length),
+        }
+    }
+
+    /// How far the tick marks reach out of the plot, towards the labels.
+    fn outside_length(&self) -> f32 {
+        match self.placement {
+            TickPlacement::Inside => 0.0,
+            TickPlacement::Outside | TickPlacement::Both => self.major_length,
+        }
+    }
+}
+
 #[derive(Clone)]
 pub(super) struct AxisWidget<'a> {
     pub range: RangeInclusive<f64>,
@@ -233,9 +449,10 @@ impl<'a> AxisWidget<'a> {
             return (response, 0.0);
         }
 
-        let Some(transform) = self.transform else {
+        let Some(transform) = &self.transform else {
             return (response, 0.0);
         };
+        self.paint_ticks_and_spine(ui, transform, axis);
         let tick_labels_thickness = self.add_tick_labels(ui, transform, axis);
 
         if self.hints.label.is_empty() {
@@ -295,21 +512,64 @@ impl<'a> AxisWidget<'a> {
         (response, tick_labels_thickness + axis_label_thickness)
     }
 
+    /// The plot value of a grid mark on this axis, which may be in a converted unit.
+    pub(super) fn plot_value(&self, axis_value: f64) -> f64 {
+        match &self.hints.conversion {
+            Some(conversion) => (conversion.inverse)(axis_value),
+            None => axis_value,
+        }
+    }
+
+    /// How many ui points there are between a grid mark and its neighbors of the same strength.
+    fn spacing_in_points(&self, transform: &PlotTransform, axis: Axis, mark: &GridMark) -> f32 {
+        if self.hints.conversion.is_none() {
+            return transform.spacing_in_points(axis, mark);
+        }
+
+        let scale = &transform.scales()[usize::from(axis)];
+        let position = |axis_value: f64| {
+            let value = self.plot_value(axis_value);
+            scale.is_valid(value).then(|| {
+                let point = match axis {
+                    Axis::X => super::PlotPoint::new(value, 0.0),
+                    Axis::Y => super::PlotPoint::new(0.0, value),
+                };
+                transform.position_from_point(&point)[usize::from(axis)]
+            })
+        };
+        let Some(mark_position) = position(mark.value) else {
+            return 0.0;
+        };
+        [mark.value - mark.step_size, mark.value + mark.step_size]
+            .into_iter()
+            .filter_map(position)
+            .map(|neighbor| (neighbor - mark_position).abs())
+            .fold(0.0, f32::max)
+    }
+
     /// Add tick labels to the axis. Returns the thickness of the axis.
-    fn add_tick_labels(&self, ui: &Ui, transform: PlotTransform, axis: Axis) -> f32 {
+    fn add_tick_labels(&self, ui: &Ui, transform: &PlotTransform, axis: Axis) -> f32 {
         let font_id = TextStyle::Body.resolve(ui.style());
         let label_spacing = self.hints.label_spacing;
-        let mut thickness: f32 = 0.0;
-        // Add tick labels:
-        for step in self.steps.iter() {
-            let text = (self.hints.formatter)(*step, &self.range);
-            if !text.is_empty() {
-                let spacing_in_points =
-                    (transform.dpos_dvalue()[usize::from(axis)] * step.step_size).abs() as f32;
+        let wrap_width = self.hints.tick_label_wrap_width.unwrap_or(f32::INFINITY);
+        let gap = self
+            .hints
+            .tick_marks
+            .map_or(0.0, |tick_marks| tick_marks.outside_length());
+
+        let labels: Vec<_> = self
+            .steps
+            .iter()
+            .filter_map(|step| {
+                let text = (self.hints.formatter)(*step, &self.range);
+                if text.is_empty() {
+                    return None;
+                }
+                let spacing_in_points = self.spacing_in_points(transform, axis, step);
 
                 if spacing_in_points <= label_spacing.min {
                     // Labels are too close together - don't paint them.
-                    continue;
+                    return None;
                 }
 
                 // Fade in labels as they get further apart:
@@ -318,63 +578,232 @@ impl<'a> AxisWidget<'a> {
                 let text_color = super::color_from_strength(ui, strength);
                 let galley = ui
                     .painter()
-                    .layout_no_wrap(text, font_id.clone(), text_color);
+                    .layout(text, font_id.clone(), text_color, wrap_width);
+                Some((step.value, galley, text_color, spacing_in_points))
+            })
+            .collect();
 
-                if spacing_in_points < galley.size()[axis as usize] {
-                    continue; // the galley won't fit (likely too wide on the X axis).
-                }
+        let fits = |galley: &Galley, spacing_in_points: f32, angle: f32| {
+            required_spacing(galley.size(), angle, axis) <= spacing_in_points
+        };
 
-                match axis {
-                    Axis::X => {
-                        thickness = thickness.max(galley.size().y);
-
-                        let projected_point = super::PlotPoint::new(step.value, 0.0);
-                        let center_x = transform.position_from_point(&projected_point).x;
-                        let y = match VPlacement::from(self.hints.placement) {
-                            VPlacement::Bottom => self.rect.min.y,
-                            VPlacement::Top => self.rect.max.y - galley.size().y,
-                        };
-                        let pos = Pos2::new(center_x - galley.size().x / 2.0, y);
-                        ui.painter().add(TextShape::new(pos, galley, text_color));
-                    }
-                    Axis::Y => {
-                        thickness = thickness.max(galley.size().x);
-
-                        let projected_point = super::PlotPoint::new(0.0, step.value);
-                        let center_y = transform.position_from_point(&projected_point).y;
-
-                        match HPlacement::from(self.hints.placement) {
-                            HPlacement::Left => {
-                                let angle = 0.0; // TODO(emilk): allow users to rotate text
-
-                                if angle == 0.0 {
-                                    let x = self.rect.max.x - galley.size().x;
-                                    let pos = Pos2::new(x, center_y - galley.size().y / 2.0);
-                                    ui.painter().add(TextShape::new(pos, galley, text_color));
-                                } else {
-                                    let right = Pos2::new(
-                                        self.rect.max.x,
-                                        center_y - galley.size().y / 2.0,
-                                    );
-                                    let width = galley.size().x;
-                                    let left =
-                                        right - Rot2::from_angle(angle) * Vec2::new(width, 0.0);
-
-                                    ui.painter().add(
-                                        TextShape::new(left, galley, text_color).with_angle(angle),
-                                    );
-                                }
-                            }
-                            HPlacement::Right => {
-                                let x = self.rect.min.x;
-                                let pos = Pos2::new(x, center_y - galley.size().y / 2.0);
-                                ui.painter().add(TextShape::new(pos, galley, text_color));
-                            }
-                        };
-                    }
-                };
+        // Rotate all labels if that makes room for some that would collide otherwise:
+        let mut angle = self.hints.tick_label_angle;
+        if let Some(auto_angle) = self.hints.auto_tick_label_angle {
+            if labels.iter().any(|(_, galley, _, spacing)| {
+                !fits(galley, *spacing, angle) && fits(galley, *spacing, auto_angle)
+            }) {
+                angle = auto_angle;
             }
         }
-        thickness
+
+        let mut thickness: f32 = 0.0;
+        for (value, galley, text_color, spacing_in_points) in labels {
+            if !fits(&galley, spacing_in_points, angle) {
+                continue; // the galley won't fit (likely too wide on the X axis).
+            }
+
+            let tick = self.screen_position(transform, axis, value);
+            let extent = rotated_size(galley.size(), angle);
+            thickness = thickness.max(match axis {
+                Axis::X => extent.y,
+                Axis::Y => extent.x,
+            });
+
+            let pos = self.tick_label_pos(galley.size(), angle, axis, tick, gap);
+            let shape = TextShape::new(pos, galley, text_color);
+            if angle == 0.0 {
+                ui.painter().add(shape);
+            } else {
+                ui.painter().add(shape.with_angle(angle));
+            }
+        }
+        thickness + gap
+    }
+
+    /// The screen position (x or y) of an axis value.
+    fn screen_position(&self, transform: &PlotTransform, axis: Axis, axis_value: f64) -> f32 {
+        let value = self.plot_value(axis_value);
+        match axis {
+            Axis::X => transform.position_from_point_x(value),
+            Axis::Y => transform.position_from_point_y(value),
+        }
     }
+
+    /// The screen coordinate (y for x axes, x for y axes) of the edge of the axis next to
+    /// the plot, and the sign of the direction away from the plot.
+    fn inner_edge(&self, axis: Axis) -> (f32, f32) {
+        match axis {
+            Axis::X => match VPlacement::from(self.hints.placement) {
+                VPlacement::Bottom => (self.rect.min.y, 1.0),
+                VPlacement::Top => (self.rect.max.y, -1.0),
+            },
+            Axis::Y => match HPlacement::from(self.hints.placement) {
+                HPlacement::Left => (self.rect.max.x, -1.0),
+                HPlacement::Right => (self.rect.min.x, 1.0),
+            },
+        }
+    }
+
+    /// Paint the tick marks and the spine, if enabled.
+    fn paint_ticks_and_spine(&self, ui: &Ui, transform: &PlotTransform, axis: Axis) {
+        let stroke = self
+            .hints
+            .tick_marks
+            .and_then(|tick_marks| tick_marks.stroke)
+            .unwrap_or_else(|| Stroke::new(1.0, ui.visuals().text_color()));
+        let (edge, outwards) = self.inner_edge(axis);
+        let segment = |along: Rangef, across: Rangef| match axis {
+            Axis::X => [pos2(along.min, across.min), pos2(along.max, across.max)],
+            Axis::Y => [pos2(across.min, along.min), pos2(across.max, along.max)],
+        };
+        let along_axis = match axis {
+            Axis::X => self.rect.x_range(),
+            Axis::Y => self.rect.y_range(),
+        };
+
+        if self.hints.spine {
+            ui.painter()
+                .line_segment(segment(along_axis, Rangef::point(edge)), stroke);
+        }
+
+        let Some(tick_marks) = self.hints.tick_marks else {
+            return;
+        };
+        for step in self.steps.iter() {
+            let spacing_in_points = self.spacing_in_points(transform, axis, step);
+            let Some(length) =
+                tick_marks.length_for_spacing(spacing_in_points, self.hints.label_spacing)
+            else {
+                continue;
+            };
+
+            let tick = self.screen_position(transform, axis, step.value);
+            if !along_axis.contains(tick) {
+                continue;
+            }
+            let (inner, outer) = tick_marks.extent(length);
+            let across = Rangef::new(edge + outwards * inner, edge + outwards * outer);
+            ui.painter()
+                .line_segment(segment(Rangef::point(tick), across), stroke);
+        }
+    }
+
+    /// Where to put a tick label of the given size (the top left corner of the unrotated galley),
+    /// so that it points at `tick` (screen x or y) and is `gap` away from the plot.
+    fn tick_label_pos(&self, size: Vec2, angle: f32, axis: Axis, tick: f32, gap: f32) -> Pos2 {
+        let rot = Rot2::from_angle(angle);
+        let (edge, outwards) = self.inner_edge(axis);
+        let edge = edge + outwards * gap;
+
+        // The point of the label that lines up with the tick (relative to the unrotated galley),
+        // and where that point goes:
+        let (anchor, target) = match axis {
+            Axis::X => {
+                let placement = VPlacement::from(self.hints.placement);
+                // Rotated labels hang off the tick by the end closest to the plot:
+                let anchor_x = if angle == 0.0 {
+                    0.5
+                } else if (angle < 0.0) == (placement == VPlacement::Bottom) {
+                    1.0
+                } else {
+                    0.0
+                };
+                (vec2(anchor_x * size.x, 0.5 * size.y), pos2(tick, edge))
+            }
+            Axis::Y => match HPlacement::from(self.hints.placement) {
+                HPlacement::Left => (vec2(size.x, 0.5 * size.y), pos2(edge, tick)),
+                HPlacement::Right => (vec2(0.0, 0.5 * size.y), pos2(edge, tick)),
+            },
+        };
+        let pos = target - rot * anchor;
+
+        // Move the label away from the plot until it no longer sticks out of the axis:
+        let corners = [Vec2::ZERO, vec2(size.x, 0.0), vec2(0.0, size.y), size];
+        let bounding_box = Rect::from_points(&corners.map(|corner| pos + rot * corner));
+        let offset = match axis {
+            Axis::X => match VPlacement::from(self.hints.placement) {
+                VPlacement::Bottom => vec2(0.0, target.y - bounding_box.min.y),
+                VPlacement::Top => vec2(0.0, target.y - bounding_box.max.y),
+            },
+            Axis::Y => match HPlacement::from(self.hints.placement) {
+                HPlacement::Left => vec2(target.x - bounding_box.max.x, 0.0),
+                HPlacement::Right => vec2(target.x - bounding_box.min.x, 0.0),
+            },
+        };
+        pos + offset
+    }
+}
+
+/// The size of the bounding box of a label of the given size, rotated by `angle`.
+fn rotated_size(size: Vec2, angle: f32) -> Vec2 {
+    let (sin, cos) = angle.sin_cos();
+    let (sin, cos) = (sin.abs(), cos.abs());
+    vec2(size.x * cos + size.y * sin, size.x * sin + size.y * cos)
+}
+
+/// How far apart along the axis two labels of the given size, rotated by `angle`, must be
+/// so that they don't overlap.
+fn required_spacing(size: Vec2, angle: f32, axis: Axis) -> f32 {
+    let (sin, cos) = angle.sin_cos();
+    let (along, across) = match axis {
+        Axis::X => (cos.abs(), sin.abs()),
+        Axis::Y => (sin.abs(), cos.abs()),
+    };
+
+    // Either the labels are side by side, or they are slanted and
+    // stacked with their baselines one line height apart:
+    let side_by_side = size.x * along + size.y * across;
+    let stacked = if 0.0 < across {
+        size.y / across
+    } else {
+        f32::INFINITY
+    };
+    side_by_side.min(stacked)
+}
+
+#[test]
+fn test_required_spacing() {
+    let size = vec2(100.0, 10.0);
+    assert_eq!(required_spacing(size, 0.0, Axis::X), 100.0);
+    assert_eq!(required_spacing(size, 0.0, Axis::Y), 10.0);
+
+    let diagonal = required_spacing(size, -std::f32::consts::FRAC_PI_4, Axis::X);
+    assert!((diagonal - 10.0 * std::f32::consts::SQRT_2).abs() < 1e-3);
+
+    let upright = rotated_size(size, std::f32::consts::FRAC_PI_2);
+    assert!((upright.x - 10.0).abs() < 1e-3 && (upright.y - 100.0).abs() < 1e-3);
+}
+
+#[test]
+fn test_tick_marks() {
+    // 1000 points for 0..=100, with grid marks every 10, 1 and 0.1:
+    let frame = Rect::from_min_size(Pos2::ZERO, vec2(1000.0, 100.0));
+    let bounds = super::PlotBounds::from_min_max([0.0, 0.0], [100.0, 1.0]);
+    let transform = PlotTransform::new(frame, bounds, false);
+    let widget = AxisWidget::new(AxisHints::new_x(), Rect::NOTHING);
+    let tick_marks = TickMarks::default().length(8.0).minor_length(4.0);
+    let length_at = |value: f64, step_size: f64| {
+        let spacing = widget.spacing_in_points(&transform, Axis::X, &GridMark { value, step_size });
+        tick_marks.length_for_spacing(spacing, widget.hints.label_spacing)
+    };
+
+    // Marks far enough apart to be labeled get major ticks, the next finer ones minor ticks:
+    assert_eq!(length_at(50.0, 10.0), Some(8.0));
+    assert_eq!(length_at(51.0, 1.0), Some(4.0));
+    assert_eq!(length_at(51.3, 0.1), None);
+    let no_minor = tick_marks.minor_length(0.0);
+    assert_eq!(
+        no_minor.length_for_spacing(10.0, widget.hints.label_spacing),
+        None
+    );
+
+    let extent = |placement| tick_marks.placement(placement).extent(8.0);
+    assert_eq!(extent(TickPlacement::Inside), (-8.0, 0.0));
+    assert_eq!(extent(TickPlacement::Outside), (0.0, 8.0));
+    assert_eq!(extent(TickPlacement::Both), (-8.0, 8.0));
+    assert_eq!(
+        tick_marks.placement(TickPlacement::Inside).outside_length(),
+        0.0
+    );
 }
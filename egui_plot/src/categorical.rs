@@ -0,0 +1,236 @@
+use std::{ops::RangeInclusive, sync::Arc};
+
+use egui::Rangef;
+
+use crate::{
+    Axis, AxisHints, Bar, BarChart, BoxElem, BoxPlot, BoxSpread, GridInput, GridMark, GridSpacer,
+    PlotPoint,
+};
+
+/// An axis of named categories, e.g. for bar charts and box plots.
+///
+/// Category `i` is placed at the plot value `i`, so the first category is at `0.0`,
+/// the second at `1.0` and so on. Grid marks are only ever placed on categories,
+/// never in between. Labels on an x axis are slanted when they would overlap,
+/// and if they still don't fit, only every 2nd, 5th, 10th, … category is labeled.
+///
+/// The quickest way to use this is [`crate::Plot::x_axis_categories`]
+/// (or [`crate::Plot::y_axis_categories`] for horizontal bars),
+/// together with [`BarChart::from_categories`] or [`BoxPlot::from_categories`].
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// use egui_plot::{BarChart, CategoricalAxis, Plot};
+///
+/// let fruit = CategoricalAxis::new(["Apples", "Bananas", "Cherries"]);
+/// let sales = BarChart::from_categories(
+///     "Sales",
+///     &fruit,
+///     [("Bananas", 7.0), ("Apples", 3.0), ("Cherries", 12.0)],
+/// );
+/// Plot::new("fruit")
+///     .x_axis_categories(&fruit)
+///     .show(ui, |plot_ui| plot_ui.bar_chart(sales));
+/// # });
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct CategoricalAxis {
+    labels: Arc<[String]>,
+}
+
+impl CategoricalAxis {
+    /// Long names on an x axis are slanted when they don't fit side by side.
+    pub(crate) const AUTO_ROTATION: f32 = -std::f32::consts::FRAC_PI_4;
+
+    /// The categories, in the order they are shown along the axis.
+    pub fn new(labels: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        Self {
+            labels: labels.into_iter().map(Into::into).collect(),
+        }
+    }
+
+    /// All categories, in order.
+    #[inline]
+    pub fn labels(&self) -> &[String] {
+        &self.labels
+    }
+
+    /// The plot value of the category with the given name.
+    pub fn position(&self, label: &str) -> Option<f64> {
+        self.labels
+            .iter()
+            .position(|l| l == label)
+            .map(|index| index as f64)
+    }
+
+    /// The category at the given plot value, if the value is (close to) a category.
+    pub fn label_at(&self, value: f64) -> Option<&str> {
+        let index = value.round();
+        if (value - index).abs() > 1e-6 || index < 0.0 {
+            return None;
+        }
+        self.labels.get(index as usize).map(String::as_str)
+    }
+
+    /// One grid mark per category, for [`crate::Plot::x_grid_spacer`]
+    /// or [`crate::Plot::y_grid_spacer`].
+    ///
+    /// Every 2nd, 5th, 10th, 20th, … category gets a larger step size,
+    /// so that those stay labeled when there is no room for all of them.
+    pub fn grid_spacer(&self) -> GridSpacer<'static> {
+        let len = self.labels.len();
+        Box::new(move |input| category_marks(len, &input))
+    }
+
+    /// An axis that labels the grid marks of [`Self::grid_spacer`] with the category names.
+    pub fn axis_hints(&self, axis: Axis) -> AxisHints<'static> {
+        let axis_self = self.clone();
+        let hints = AxisHints::new(axis)
+            .formatter(move |mark, range| axis_self.format_mark(mark, range))
+            .label_spacing(Self::label_spacing());
+        match axis {
+            Axis::X => hints.auto_rotate_tick_labels(Self::AUTO_ROTATION),
+            Axis::Y => hints,
+        }
+    }
+
+    /// A label for the hover tooltip, see [`crate::Plot::label_formatter`].
+    ///
+    /// Shows the category along the given axis, and the value along the other one.
+    pub fn label_formatter(&self, axis: Axis) -> impl Fn(&str, &PlotPoint) -> String + 'static {
+        let axis_self = self.clone();
+        move |name, value| {
+            let prefix = if name.is_empty() {
+                String::new()
+            } else {
+                format!("{name}\n")
+            };
+            let (category, value) = match axis {
+                Axis::X => (value.x, value.y),
+                Axis::Y => (value.y, value.x),
+            };
+            let category = axis_self.label_at(category.round()).unwrap_or_default();
+            format!("{prefix}{category}\n{value:.3}")
+        }
+    }
+
+    /// The category name of a grid mark, or an empty string for marks between categories.
+    pub fn format_mark(&self, mark: GridMark, _range: &RangeInclusive<f64>) -> String {
+        self.label_at(mark.value).unwrap_or_default().to_owned()
+    }
+
+    /// Category names can be short, so they are allowed closer together than numbers.
+    /// Names that are wider than the space they get are skipped.
+    pub(crate) fn label_spacing() -> Rangef {
+        Rangef::new(10.0, 20.0)
+    }
+}
+
+/// Grid marks on the categories `0..len` within the bounds, with larger steps on every
+/// 2nd, 5th, 10th, … category.
+fn category_marks(len: usize, input: &GridInput) -> Vec<GridMark> {
+    let (min, max) = input.bounds;
+    let last = len as f64 - 1.0;
+    let first = min.ceil().max(0.0);
+    let end = max.floor().min(last);
+    if !end.is_finite() || end < first {
+        return Vec::new();
+    }
+
+    // The 1-2-5 sequence of strides, up to one that spans all categories:
+    let mut strides = vec![1_usize];
+    let mut stride = 1_usize;
+    while stride < len {
+        let decade = 10_usize.pow(stride.ilog10());
+        stride = match stride / decade {
+            1 => 2 * decade,
+            2 => 5 * decade,
+            _ => 10 * decade,
+        };
+        strides.push(stride);
+    }
+
+    (first as usize..=end as usize)
+        .map(|index| {
+            let stride = strides
+                .iter()
+                .rev()
+                .find(|&&stride| index % stride == 0)
+                .copied()
+                .unwrap_or(1);
+            GridMark {
+                value: index as f64,
+                step_size: stride as f64,
+            }
+        })
+        .collect()
+}
+
+impl BarChart {
+    /// A bar chart with one bar per `(category, value)` pair, placed at the category's position.
+    ///
+    /// Each bar is named after its category. Categories not in `axis` are ignored.
+    pub fn from_categories(
+        name: impl Into<String>,
+        axis: &CategoricalAxis,
+        values: impl IntoIterator<Item = (impl AsRef<str>, f64)>,
+    ) -> Self {
+        let bars = values
+            .into_iter()
+            .filter_map(|(category, value)| {
+                let category = category.as_ref();
+                let argument = axis.position(category)?;
+                Some(Bar::new(argument, value).name(category))
+            })
+            .collect();
+        Self::new(name, bars)
+    }
+}
+
+impl BoxPlot {
+    /// A box plot with one box per `(category, spread)` pair, placed at the category's position.
+    ///
+    /// Each box is named after its category. Categories not in `axis` are ignored.
+    pub fn from_categories(
+        name: impl Into<String>,
+        axis: &CategoricalAxis,
+        spreads: impl IntoIterator<Item = (impl AsRef<str>, BoxSpread)>,
+    ) -> Self {
+        let boxes = spreads
+            .into_iter()
+            .filter_map(|(category, spread)| {
+                let category = category.as_ref();
+                let argument = axis.position(category)?;
+                Some(BoxElem::new(argument, spread).name(category))
+            })
+            .collect();
+        Self::new(name, boxes)
+    }
+}
+
+#[test]
+fn test_category_marks() {
+    let input = |min, max| GridInput {
+        bounds: (min, max),
+        base_step_size: 0.1,
+    };
+
+    let marks = category_marks(12, &input(-0.5, 11.5));
+    let values: Vec<f64> = marks.iter().map(|mark| mark.value).collect();
+    assert_eq!(values, (0..12).map(f64::from).collect::<Vec<_>>());
+    assert_eq!(marks[0].step_size, 20.0);
+    assert_eq!(marks[5].step_size, 5.0);
+    assert_eq!(marks[6].step_size, 2.0);
+    assert_eq!(marks[10].step_size, 10.0);
+    assert_eq!(marks[11].step_size, 1.0);
+
+    assert_eq!(category_marks(12, &input(2.2, 3.8)).len(), 1);
+    assert!(category_marks(12, &input(12.5, 20.0)).is_empty());
+    assert!(category_marks(0, &input(-1.0, 1.0)).is_empty());
+
+    let axis = CategoricalAxis::new(["a", "b"]);
+    assert_eq!(axis.position("b"), Some(1.0));
+    assert_eq!(axis.label_at(1.0), Some("b"));
+    assert_eq!(axis.label_at(0.5), None);
+    assert_eq!(axis.label_at(2.0), None);
+}
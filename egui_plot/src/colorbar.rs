@@ -0,0 +1,259 @@
+use std::{ops::RangeInclusive, sync::Arc};
+
+use egui::{
+    Color32, Id, Mesh, Pos2, Rect, Response, Sense, Shape, Stroke, StrokeKind, Ui, UiBuilder,
+    WidgetText, epaint::Vertex, pos2, vec2,
+};
+
+use crate::{
+    Axis, AxisHints, Colormap, GridInput, GridMark, GridSpacer, HPlacement, PlotBounds, PlotItem,
+    PlotTransform, axis::AxisWidget, grid_marks, log_grid_spacer,
+};
+
+/// How many pieces a continuous colormap is split into when painting it.
+const NUM_SEGMENTS: usize = 64;
+
+/// Tick labels closer together than this are not shown.
+const MIN_TICK_SPACING: f32 = 8.0;
+
+/// Shows which color a [`Colormap`] gives to which value, as a bar with tick labels.
+///
+/// A colorbar can be attached to the side of a [`crate::Plot`] with [`crate::Plot::colorbar`],
+/// where it gets the height of the plot area, or shown on its own with [`Self::show`].
+/// Attached to a plot, it can follow the colors of one of its items, see [`Self::for_item`].
+///
+/// The ticks are labeled like a y axis, so they can be customized with the same
+/// [`AxisHints`] formatters and grid spacers.
+/// By default the labels are on the right of the bar; use [`AxisHints::placement`] with
+/// [`HPlacement::Left`] to put them (and an attached colorbar) on the left instead.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// use egui_plot::{Colorbar, Colormap, Plot};
+///
+/// Plot::new("temperature")
+///     .colorbar(Colorbar::new(Colormap::inferno(), 0.0..=40.0).label("°C"))
+///     .show(ui, |plot_ui| {});
+/// # });
+/// ```
+pub struct Colorbar<'a> {
+    colormap: Colormap,
+    range: RangeInclusive<f64>,
+    hints: AxisHints<'a>,
+    grid_spacer: GridSpacer<'a>,
+    bar_width: f32,
+    height: Option<f32>,
+    item: Option<Id>,
+    id_salt: Id,
+}
+
+impl<'a> Colorbar<'a> {
+    /// A colorbar for `colormap` spanning the values in `range`.
+    pub fn new(colormap: Colormap, range: RangeInclusive<f64>) -> Self {
+        Self {
+            colormap,
+            range,
+            hints: AxisHints::new(Axis::Y).placement(HPlacement::Right),
+            grid_spacer: log_grid_spacer(10),
+            bar_width: 16.0,
+            height: None,
+            item: None,
+            id_salt: Id::new("colorbar"),
+        }
+    }
+
+    /// A colorbar for the colormap and value range of the item with the given id
+    /// in the plot it is attached to, like a [`crate::Heatmap`] or [`crate::Points`]
+    /// colored with [`crate::Points::color_by_value`].
+    ///
+    /// The id of an item is its name, unless it is set with the `id` builder method of the item.
+    /// Until the plot has such an item, the colorbar shows the default [`Colormap`] over `0..=1`.
+    pub fn for_item(id: impl Into<Id>) -> Self {
+        let id = id.into();
+        Self {
+            item: Some(id),
+            id_salt: id.with("colorbar"),
+            ..Self::new(Colormap::default(), 0.0..=1.0)
+        }
+    }
+
+    /// Text shown next to the tick labels, e.g. the quantity and unit of the values.
+    #[inline]
+    pub fn label(mut self, label: impl Into<WidgetText>) -> Self {
+        self.hints.label = label.into();
+        self
+    }
+
+    /// Customize the tick labels, like those of a y axis.
+    ///
+    /// The placement of the hints decides which side of the bar the labels are on.
+    #[inline]
+    pub fn axis_hints(mut self, hints: AxisHints<'a>) -> Self {
+        self.hints = hints;
+        self
+    }
+
+    /// Where to put the ticks, see [`crate::Plot::y_grid_spacer`].
+    ///
+    /// Default: [`log_grid_spacer`] with base 10.
+    #[inline]
+    pub fn grid_spacer(mut self, spacer: impl Fn(GridInput) -> Vec<GridMark> + 'a) -> Self {
+        self.grid_spacer = Box::new(spacer);
+        self
+    }
+
+    /// Width of the colored bar, without the tick labels. Default: `16.0`.
+    #[inline]
+    pub fn bar_width(mut self, bar_width: f32) -> Self {
+        self.bar_width = bar_width;
+        self
+    }
+
+    /// Height of a colorbar shown with [`Self::show`].
+    /// By default it fills the height of the ui it is in.
+    ///
+    /// A colorbar attached to a plot always has the height of the plot area.
+    #[inline]
+    pub fn height(mut self, height: f32) -> Self {
+        self.height = Some(height);
+        self
+    }
+
+    /// Makes the colorbar unique within its parent ui when shown with [`Self::show`],
+    /// e.g. the id of the plot it goes with. The size of the tick labels is remembered under it.
+    #[inline]
+    pub fn id_salt(mut self, id_salt: impl std::hash::Hash) -> Self {
+        self.id_salt = Id::new(id_salt);
+        self
+    }
+
+    /// Take the colormap and value range from the item this colorbar is for, if any,
+    /// as it is shown within the `visible` bounds.
+    pub(crate) fn follow_item(&mut self, items: &[Box<dyn PlotItem + '_>], visible: &PlotBounds) {
+        let Some(id) = self.item else {
+            return;
+        };
+        if let Some((colormap, range)) = items
+            .iter()
+            .find(|item| item.id() == id)
+            .and_then(|item| item.color_mapping(visible))
+        {
+            self.colormap = colormap;
+            self.range = range;
+        }
+    }
+
+    /// Is the colorbar on the right of what it is attached to?
+    pub(crate) fn is_on_right(&self) -> bool {
+        HPlacement::from(self.hints.placement) == HPlacement::Right
+    }
+
+    /// The width of the colorbar, given how much room the tick labels took the previous frame.
+    pub(crate) fn width(&self, remembered_thickness: f32) -> f32 {
+        self.bar_width + self.hints.min_thickness.max(remembered_thickness)
+    }
+
+    /// Paint the colorbar into `rect`, and return the thickness of its tick labels.
+    pub(crate) fn paint(&self, ui: &mut Ui, rect: Rect) -> f32 {
+        let bar_rect = if self.is_on_right() {
+            Rect::from_x_y_ranges(rect.left()..=rect.left() + self.bar_width, rect.y_range())
+        } else {
+            Rect::from_x_y_ranges(rect.right() - self.bar_width..=rect.right(), rect.y_range())
+        };
+        let labels_rect = if self.is_on_right() {
+            Rect::from_x_y_ranges(bar_rect.right()..=rect.right(), rect.y_range())
+        } else {
+            Rect::from_x_y_ranges(rect.left()..=bar_rect.left(), rect.y_range())
+        };
+
+        let (start, end) = (*self.range.start(), *self.range.end());
+        ui.painter()
+            .add(self.bar_mesh(bar_rect, start.min(end), start.max(end)));
+        ui.painter().rect_stroke(
+            bar_rect,
+            0.0,
+            Stroke::new(1.0, ui.visuals().widgets.noninteractive.bg_stroke.color),
+            StrokeKind::Inside,
+        );
+
+        let transform = PlotTransform::new(
+            bar_rect,
+            PlotBounds::from_min_max([0.0, start.min(end)], [1.0, start.max(end)]),
+            false,
+        );
+        let mut widget = AxisWidget::new(self.hints.clone(), labels_rect);
+        widget.range = transform.bounds().range_y();
+        widget.steps = Arc::new(grid_marks(
+            &transform,
+            Axis::Y,
+            &self.grid_spacer,
+            MIN_TICK_SPACING,
+        ));
+        widget.transform = Some(transform);
+        let (_response, thickness) = widget.ui(ui, Axis::Y);
+        thickness
+    }
+
+    /// The bar, from the lowest value at the bottom to the highest at the top.
+    fn bar_mesh(&self, rect: Rect, min: f64, max: f64) -> Shape {
+        let mut mesh = Mesh::default();
+        let mut add_band = |bottom: f32, top: f32, bottom_color: Color32, top_color: Color32| {
+            let index = mesh.vertices.len() as u32;
+            for (pos, color) in [
+                (pos2(rect.left(), bottom), bottom_color),
+                (pos2(rect.right(), bottom), bottom_color),
+                (pos2(rect.right(), top), top_color),
+                (pos2(rect.left(), top), top_color),
+            ] {
+                mesh.vertices.push(Vertex {
+                    pos,
+                    uv: Pos2::ZERO,
+                    color,
+                });
+            }
+            mesh.add_triangle(index, index + 1, index + 2);
+            mesh.add_triangle(index, index + 2, index + 3);
+        };
+        let y = |t: f64| rect.bottom() - t as f32 * rect.height();
+        let color = |t: f64| {
+            self.colormap
+                .color_for_value(min + t * (max - min), &self.range)
+        };
+
+        if self.colormap.is_discrete() {
+            let num_bands = self.colormap.colors().len();
+            for band in 0..num_bands {
+                let (t0, t1) = (
+                    band as f64 / num_bands as f64,
+                    (band + 1) as f64 / num_bands as f64,
+                );
+                let band_color = color(0.5 * (t0 + t1));
+                add_band(y(t0), y(t1), band_color, band_color);
+            }
+        } else {
+            for segment in 0..NUM_SEGMENTS {
+                let t0 = segment as f64 / NUM_SEGMENTS as f64;
+                let t1 = (segment + 1) as f64 / NUM_SEGMENTS as f64;
+                add_band(y(t0), y(t1), color(t0), color(t1));
+            }
+        }
+        Shape::mesh(mesh)
+    }
+
+    /// Show the colorbar on its own, e.g. next to a plot in a [`crate::PlotGrid`].
+    pub fn show(self, ui: &mut Ui) -> Response {
+        let id = ui.make_persistent_id(self.id_salt);
+        let remembered_thickness: f32 = ui.data(|data| data.get_temp(id)).unwrap_or_default();
+        let height = self
+            .height
+            .unwrap_or_else(|| ui.available_size_before_wrap().y);
+        let size = vec2(self.width(remembered_thickness), height);
+        let (rect, response) = ui.allocate_exact_size(size, Sense::hover());
+        if ui.is_rect_visible(rect) {
+            let mut bar_ui = ui.new_child(UiBuilder::new().id_salt(id).max_rect(rect));
+            let thickness = self.paint(&mut bar_ui, rect);
+            ui.data_mut(|data| data.insert_temp::<f32>(id, thickness));
+        }
+        response
+    }
+}
@@ -0,0 +1,142 @@
+use std::ops::RangeInclusive;
+
+use egui::Color32;
+
+/// Maps values to colors, e.g. for a [`crate::Heatmap`] or a [`crate::Colorbar`].
+///
+/// A continuous colormap blends between its colors, which are spread evenly over the range.
+/// A discrete colormap splits the range into one band per color.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Colormap {
+    colors: Vec<Color32>,
+    discrete: bool,
+}
+
+impl Default for Colormap {
+    fn default() -> Self {
+        Self::viridis()
+    }
+}
+
+impl Colormap {
+    /// A continuous colormap, blending from the first color to the last.
+    pub fn new(colors: impl IntoIterator<Item = Color32>) -> Self {
+        Self {
+            colors: colors.into_iter().collect(),
+            discrete: false,
+        }
+    }
+
+    /// A discrete colormap, with one band of equal width per color.
+    pub fn discrete(colors: impl IntoIterator<Item = Color32>) -> Self {
+        Self {
+            colors: colors.into_iter().collect(),
+            discrete: true,
+        }
+    }
+
+    /// Perceptually uniform, from dark blue through green to yellow.
+    pub fn viridis() -> Self {
+        Self::new(
+            [
+                0x440154, 0x472d7b, 0x3b528b, 0x2c728e, 0x21918c, 0x28ae80, 0x5ec962, 0xaddc30,
+                0xfde725,
+            ]
+            .map(color_from_hex),
+        )
+    }
+
+    /// Perceptually uniform, from black through red to light yellow.
+    pub fn inferno() -> Self {
+        Self::new(
+            [
+                0x000004, 0x1f0c48, 0x550f6d, 0x88226a, 0xba3655, 0xe35933, 0xf98e09, 0xf8c932,
+                0xfcffa4,
+            ]
+            .map(color_from_hex),
+        )
+    }
+
+    /// Diverging, from blue through light gray to red, for values around a midpoint.
+    pub fn cool_warm() -> Self {
+        Self::new([0x3b4cc0, 0x7b9ff9, 0xc0d4f5, 0xf2cbb7, 0xee8468, 0xb40426].map(color_from_hex))
+    }
+
+    /// From black to white.
+    pub fn grayscale() -> Self {
+        Self::new([Color32::BLACK, Color32::WHITE])
+    }
+
+    /// The colors of the colormap, from the start of the range to the end.
+    #[inline]
+    pub fn colors(&self) -> &[Color32] {
+        &self.colors
+    }
+
+    /// Whether the colormap has bands of flat color instead of a blend.
+    #[inline]
+    pub fn is_discrete(&self) -> bool {
+        self.discrete
+    }
+
+    /// The color at `t`, from `0.0` at the start of the colormap to `1.0` at the end.
+    ///
+    /// Values outside of `0.0..=1.0` get the color at the nearest end.
+    /// `NaN` is transparent.
+    pub fn color_at(&self, t: f64) -> Color32 {
+        let (Some(first), Some(last)) = (self.colors.first(), self.colors.last()) else {
+            return Color32::TRANSPARENT;
+        };
+        if t.is_nan() {
+            return Color32::TRANSPARENT;
+        }
+        let t = t.clamp(0.0, 1.0);
+        let last_index = self.colors.len() - 1;
+        if self.discrete {
+            let index = (t * self.colors.len() as f64) as usize;
+            return self.colors[index.min(last_index)];
+        }
+        if last_index == 0 {
+            return *first;
+        }
+        let position = t * last_index as f64;
+        let index = position.floor() as usize;
+        if last_index <= index {
+            return *last;
+        }
+        self.colors[index].lerp_to_gamma(self.colors[index + 1], (position - index as f64) as f32)
+    }
+
+    /// The color of `value` when the colormap spans `range`.
+    pub fn color_for_value(&self, value: f64, range: &RangeInclusive<f64>) -> Color32 {
+        let span = range.end() - range.start();
+        let t = if span == 0.0 {
+            0.5
+        } else {
+            (value - range.start()) / span
+        };
+        self.color_at(t)
+    }
+}
+
+fn color_from_hex(rgb: u32) -> Color32 {
+    let [_, r, g, b] = rgb.to_be_bytes();
+    Color32::from_rgb(r, g, b)
+}
+
+#[test]
+fn test_colormap() {
+    let gray = Colormap::grayscale();
+    assert_eq!(gray.color_at(-1.0), Color32::BLACK);
+    assert_eq!(gray.color_at(2.0), Color32::WHITE);
+    assert_eq!(
+        gray.color_for_value(5.0, &(0.0..=10.0)),
+        Color32::from_gray(128)
+    );
+
+    let traffic_light = Colormap::discrete([Color32::GREEN, Color32::YELLOW, Color32::RED]);
+    assert_eq!(traffic_light.color_at(0.3), Color32::GREEN);
+    assert_eq!(traffic_light.color_at(0.5), Color32::YELLOW);
+    assert_eq!(traffic_light.color_at(1.0), Color32::RED);
+    assert_eq!(traffic_light.color_at(f64::NAN), Color32::TRANSPARENT);
+}
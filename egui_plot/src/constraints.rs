@@ -0,0 +1,193 @@
+use std::ops::RangeInclusive;
+
+use egui::Vec2b;
+
+use crate::PlotBounds;
+
+/// Limits on where the user can pan and zoom a plot to.
+///
+/// Set through [`crate::Plot::x_limits`], [`crate::Plot::x_span_limits`],
+/// [`crate::Plot::keep_data_in_view`] and their y counterparts.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub(crate) struct BoundsConstraints {
+    /// Hard outer limits per axis.
+    pub limits: [Option<RangeInclusive<f64>>; 2],
+
+    /// Smallest and largest visible span per axis.
+    pub span_limits: [Option<RangeInclusive<f64>>; 2],
+
+    /// Don't allow panning away from the data.
+    pub keep_data_in_view: Vec2b,
+}
+
+impl BoundsConstraints {
+    pub fn is_active(&self) -> bool {
+        self.limits.iter().any(Option::is_some)
+            || self.span_limits.iter().any(Option::is_some)
+            || self.keep_data_in_view.any()
+    }
+
+    /// Constrain `bounds`, which were changed from `previous` by panning or zooming.
+    ///
+    /// `data` are the bounds of the plot items, used by [`Self::keep_data_in_view`].
+    pub fn apply(
+        &self,
+        previous: &PlotBounds,
+        bounds: &PlotBounds,
+        data: &PlotBounds,
+    ) -> PlotBounds {
+        let mut constrained = *bounds;
+        for axis in 0..2 {
+            let range = (bounds.min[axis], bounds.max[axis]);
+            if !(range.0.is_finite() && range.1.is_finite()) {
+                continue;
+            }
+            let (mut min, mut max) =
+                self.constrain_span(axis, (previous.min[axis], previous.max[axis]), range);
+
+            if self.keep_data_in_view[axis] && data.min[axis] <= data.max[axis] {
+                (min, max) = keep_in_view((min, max), (data.min[axis], data.max[axis]));
+            }
+            if let Some(limits) = &self.limits[axis] {
+                (min, max) = fit_within((min, max), (*limits.start(), *limits.end()));
+            }
+
+            if min < max {
+                constrained.min[axis] = min;
+                constrained.max[axis] = max;
+            } else if previous.min[axis] < previous.max[axis] {
+                // E.g. a minimum span too small to tell the ends apart at these values:
+                constrained.min[axis] = previous.min[axis];
+                constrained.max[axis] = previous.max[axis];
+            }
+        }
+        constrained
+    }
+
+    /// Clamp the span of `(min, max)` to the span limits.
+    ///
+    /// If the span came from zooming `previous`, the zoom is stopped part way,
+    /// so that the point under the pointer stays put.
+    fn constrain_span(
+        &self,
+        axis: usize,
+        previous: (f64, f64),
+        (min, max): (f64, f64),
+    ) -> (f64, f64) {
+        let Some(span_limits) = &self.span_limits[axis] else {
+            return (min, max);
+        };
+        let span = max - min;
+        let target = span.clamp(*span_limits.start(), *span_limits.end());
+        if target == span {
+            return (min, max);
+        }
+
+        let previous_span = previous.1 - previous.0;
+        let t = (target - previous_span) / (span - previous_span);
+        if previous_span.is_finite() && (0.0..=1.0).contains(&t) {
+            (
+                previous.0 + t * (min - previous.0),
+                previous.1 + t * (max - previous.1),
+            )
+        } else {
+            let center = 0.5 * (min + max);
+            (center - 0.5 * target, center + 0.5 * target)
+        }
+    }
+}
+
+/// The range with its ends swapped if it is reversed.
+pub(crate) fn ordered(range: RangeInclusive<f64>) -> RangeInclusive<f64> {
+    let (start, end) = range.into_inner();
+    if end < start {
+        end..=start
+    } else {
+        start..=end
+    }
+}
+
+/// Sanitize the argument of [`crate::Plot::x_limits`]: it is ordered,
+/// and ignored if it is empty (or NaN).
+pub(crate) fn limits(range: RangeInclusive<f64>) -> Option<RangeInclusive<f64>> {
+    let (start, end) = ordered(range).into_inner();
+    (start < end).then_some(start..=end)
+}
+
+/// Sanitize the argument of [`crate::Plot::x_span_limits`]: it is ordered with a positive
+/// minimum, and ignored if it doesn't allow any positive span.
+pub(crate) fn span_limits(range: RangeInclusive<f64>) -> Option<RangeInclusive<f64>> {
+    let (start, end) = ordered(range).into_inner();
+    (0.0 < end).then(|| start.max(f64::MIN_POSITIVE).min(end)..=end)
+}
+
+/// Move `(min, max)` inside `(lower, upper)`, shrinking it if it doesn't fit.
+fn fit_within((min, max): (f64, f64), (lower, upper): (f64, f64)) -> (f64, f64) {
+    if upper - lower <= max - min {
+        (lower, upper)
+    } else if min < lower {
+        (lower, max + lower - min)
+    } else if upper < max {
+        (min + upper - max, upper)
+    } else {
+        (min, max)
+    }
+}
+
+/// Move `(min, max)` so that it shows as much of `(lower, upper)` as it can:
+/// it stays inside the data when zoomed in, and contains all of it when zoomed out.
+fn keep_in_view((min, max): (f64, f64), (lower, upper): (f64, f64)) -> (f64, f64) {
+    if max - min <= upper - lower {
+        fit_within((min, max), (lower, upper))
+    } else if lower < min {
+        (lower, max + lower - min)
+    } else if max < upper {
+        (min + upper - max, upper)
+    } else {
+        (min, max)
+    }
+}
+
+#[test]
+fn test_bounds_constraints() {
+    let bounds = |min: f64, max: f64| PlotBounds::from_min_max([min, min], [max, max]);
+    let constraints = BoundsConstraints {
+        limits: [Some(0.0..=100.0), None],
+        span_limits: [Some(1.0..=50.0), None],
+        keep_data_in_view: Vec2b::new(false, true),
+    };
+    let data = bounds(10.0, 20.0);
+
+    // Panning past the limit stops at the limit:
+    let panned = constraints.apply(&bounds(0.0, 10.0), &bounds(-5.0, 5.0), &data);
+    assert_eq!((panned.min[0], panned.max[0]), (0.0, 10.0));
+
+    // Panning away from the data is stopped at the data:
+    assert_eq!((panned.min[1], panned.max[1]), (10.0, 20.0));
+
+    // Zooming in around 4.0 stops at the minimum span, still around 4.0:
+    let zoomed = constraints.apply(&bounds(0.0, 10.0), &bounds(3.8, 4.3), &data);
+    assert!((zoomed.min[0] - 3.6).abs() < 1e-9 && (zoomed.max[0] - 4.6).abs() < 1e-9);
+
+    // Zooming out past the data keeps all of it visible:
+    let zoomed_out = constraints.apply(&bounds(10.0, 20.0), &bounds(18.0, 48.0), &data);
+    assert_eq!((zoomed_out.min[1], zoomed_out.max[1]), (10.0, 40.0));
+
+    assert_eq!(ordered(100.0..=0.0), 0.0..=100.0);
+    assert_eq!(ordered(1.0..=50.0), 1.0..=50.0);
+
+    assert_eq!(limits(100.0..=0.0), Some(0.0..=100.0));
+    assert_eq!(limits(3.0..=3.0), None);
+    assert_eq!(limits(f64::NAN..=1.0), None);
+    assert_eq!(span_limits(0.0..=2.0), Some(f64::MIN_POSITIVE..=2.0));
+    assert_eq!(span_limits(0.0..=0.0), None);
+    assert_eq!(span_limits(-5.0..=-1.0), None);
+
+    // A minimum span that can't be represented around the values keeps the previous bounds:
+    let tiny_span = BoundsConstraints {
+        span_limits: [Some(f64::MIN_POSITIVE..=1.0), None],
+        ..Default::default()
+    };
+    let zoomed = tiny_span.apply(&bounds(2.0, 4.0), &bounds(3.0, 3.0), &data);
+    assert_eq!((zoomed.min[0], zoomed.max[0]), (2.0, 4.0));
+}
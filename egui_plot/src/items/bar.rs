@@ -192,7 +192,7 @@ impl RectElement for Bar {
     }
 
     fn default_values_format(&self, transform: &PlotTransform) -> String {
-        let scale = transform.dvalue_dpos();
+        let scale = transform.dvalue_dpos_at(&self.point_at(self.argument, self.value));
         let scale = match self.orientation {
             Orientation::Horizontal => scale[0],
             Orientation::Vertical => scale[1],
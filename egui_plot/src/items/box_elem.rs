@@ -279,7 +279,7 @@ impl RectElement for BoxElem {
     }
 
     fn default_values_format(&self, transform: &PlotTransform) -> String {
-        let scale = transform.dvalue_dpos();
+        let scale = transform.dvalue_dpos_at(&self.point_at(self.argument, self.spread.median));
         let scale = match self.orientation {
             Orientation::Horizontal => scale[0],
             Orientation::Vertical => scale[1],
@@ -0,0 +1,236 @@
+use egui::emath::NumExt as _;
+use egui::epaint::{Color32, CornerRadius, RectShape, Shape, Stroke};
+
+use crate::{CandlestickChart, Cursor, PlotPoint, PlotTransform};
+
+use super::{Orientation, PlotConfig, RectElement, add_rulers_and_text};
+
+/// How the candles of a [`CandlestickChart`] are drawn.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum CandleStyle {
+    /// Candles with a filled body.
+    #[default]
+    Filled,
+
+    /// Rising candles with an outlined body, falling candles with a filled one.
+    Hollow,
+
+    /// OHLC bars: a line from low to high, with a tick to the left at the open
+    /// and a tick to the right at the close.
+    Ohlc,
+}
+
+/// One time bucket in a [`CandlestickChart`], with its open, high, low and close values.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Candle {
+    /// Name of plot element in the diagram (annotated by default formatter).
+    pub name: String,
+
+    /// Position on the x axis, usually the time of the bucket.
+    pub argument: f64,
+
+    /// Value at the start of the bucket.
+    pub open: f64,
+
+    /// Highest value in the bucket.
+    pub high: f64,
+
+    /// Lowest value in the bucket.
+    pub low: f64,
+
+    /// Value at the end of the bucket.
+    pub close: f64,
+
+    /// Width of the body, in plot units.
+    pub width: f64,
+}
+
+impl Candle {
+    /// Create a candle at `argument`.
+    pub fn new(argument: f64, open: f64, high: f64, low: f64, close: f64) -> Self {
+        Self {
+            name: String::new(),
+            argument,
+            open,
+            high,
+            low,
+            close,
+            width: 0.6,
+        }
+    }
+
+    /// Name of this candle.
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    pub fn name(mut self, name: impl ToString) -> Self {
+        self.name = name.to_string();
+        self
+    }
+
+    /// Set the width of the body, in plot units.
+    #[inline]
+    pub fn width(mut self, width: f64) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Did the value go up (or stay the same) in this bucket?
+    pub fn is_rising(&self) -> bool {
+        self.close >= self.open
+    }
+
+    pub(super) fn add_shapes(
+        &self,
+        parent: &CandlestickChart,
+        transform: &PlotTransform,
+        highlighted: bool,
+        shapes: &mut Vec<Shape>,
+    ) {
+        let color = if self.is_rising() {
+            parent.up_color
+        } else {
+            parent.down_color
+        };
+        let mut stroke = Stroke::new(1.0, color);
+        if highlighted {
+            stroke.width *= 2.0;
+        }
+
+        let line_between = |argument1, value1, argument2, value2| {
+            Shape::line_segment(
+                [
+                    transform.position_from_point(&PlotPoint::new(argument1, value1)),
+                    transform.position_from_point(&PlotPoint::new(argument2, value2)),
+                ],
+                stroke,
+            )
+        };
+        let half_width = self.width / 2.0;
+
+        if parent.style == CandleStyle::Ohlc {
+            shapes.push(line_between(
+                self.argument,
+                self.low,
+                self.argument,
+                self.high,
+            ));
+            shapes.push(line_between(
+                self.argument - half_width,
+                self.open,
+                self.argument,
+                self.open,
+            ));
+            shapes.push(line_between(
+                self.argument,
+                self.close,
+                self.argument + half_width,
+                self.close,
+            ));
+            return;
+        }
+
+        // The wicks stop at the body, so they don't show through a hollow one:
+        let body_bottom = self.open.min(self.close);
+        let body_top = self.open.max(self.close);
+        if self.low < body_bottom {
+            shapes.push(line_between(
+                self.argument,
+                self.low,
+                self.argument,
+                body_bottom,
+            ));
+        }
+        if body_top < self.high {
+            shapes.push(line_between(
+                self.argument,
+                body_top,
+                self.argument,
+                self.high,
+            ));
+        }
+
+        let fill = if parent.style == CandleStyle::Hollow && self.is_rising() {
+            Color32::TRANSPARENT
+        } else {
+            color
+        };
+        let mut rect = transform.rect_from_values(
+            &PlotPoint::new(self.argument - half_width, body_bottom),
+            &PlotPoint::new(self.argument + half_width, body_top),
+        );
+        // A candle that closes where it opened still gets a visible body:
+        rect.max.y = rect.max.y.at_least(rect.min.y + stroke.width);
+        shapes.push(Shape::Rect(RectShape::new(
+            rect,
+            CornerRadius::ZERO,
+            fill,
+            stroke,
+            egui::StrokeKind::Inside,
+        )));
+    }
+
+    pub(super) fn add_rulers_and_text(
+        &self,
+        parent: &CandlestickChart,
+        plot: &PlotConfig<'_>,
+        shapes: &mut Vec<Shape>,
+        cursors: &mut Vec<Cursor>,
+    ) {
+        let text: Option<String> = parent
+            .element_formatter
+            .as_ref()
+            .map(|fmt| fmt(self, parent));
+
+        add_rulers_and_text(self, plot, text, shapes, cursors);
+    }
+}
+
+impl RectElement for Candle {
+    fn name(&self) -> &str {
+        self.name.as_str()
+    }
+
+    fn bounds_min(&self) -> PlotPoint {
+        PlotPoint::new(self.argument - self.width / 2.0, self.low)
+    }
+
+    fn bounds_max(&self) -> PlotPoint {
+        PlotPoint::new(self.argument + self.width / 2.0, self.high)
+    }
+
+    fn values_with_ruler(&self) -> Vec<PlotPoint> {
+        [self.open, self.high, self.low, self.close]
+            .map(|value| PlotPoint::new(self.argument, value))
+            .to_vec()
+    }
+
+    fn orientation(&self) -> Orientation {
+        Orientation::Vertical
+    }
+
+    fn default_values_format(&self, transform: &PlotTransform) -> String {
+        let scale = transform.dvalue_dpos_at(&PlotPoint::new(self.argument, self.close));
+        let decimals = ((-scale[1].abs().log10()).ceil().at_least(0.0) as usize)
+            .at_most(6)
+            .at_least(1);
+        format!(
+            "Open = {open:.decimals$}\
+             \nHigh = {high:.decimals$}\
+             \nLow = {low:.decimals$}\
+             \nClose = {close:.decimals$}",
+            open = self.open,
+            high = self.high,
+            low = self.low,
+            close = self.close,
+        )
+    }
+}
+
+#[test]
+fn test_candle_bounds() {
+    let candle = Candle::new(10.0, 3.0, 5.0, 1.0, 2.0).width(2.0);
+    assert!(!candle.is_rising());
+    assert_eq!(candle.bounds_min(), PlotPoint::new(9.0, 1.0));
+    assert_eq!(candle.bounds_max(), PlotPoint::new(11.0, 5.0));
+    assert!(Candle::new(0.0, 2.0, 2.0, 2.0, 2.0).is_rising());
+}
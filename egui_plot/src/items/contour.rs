@@ -0,0 +1,405 @@
+//! Marching squares over a regular grid of samples, for [`super::Contour`].
+
+use ahash::HashMap;
+
+use super::PlotPoint;
+use crate::PlotBounds;
+
+/// Values sampled on a regular grid, with row 0 at the bottom.
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct SampledField {
+    values: Vec<f64>,
+    num_rows: usize,
+    num_columns: usize,
+
+    /// The position of the sample in row 0, column 0.
+    origin: [f64; 2],
+
+    /// The distance between two samples along x and y.
+    step: [f64; 2],
+}
+
+/// An edge between two neighboring samples: the sample at `(column, row)` and the one to the right
+/// of it (horizontal) or above it (vertical).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+struct Edge {
+    column: usize,
+    row: usize,
+    vertical: bool,
+}
+
+impl SampledField {
+    /// The values of grid cells, given row by row, sampled at the cell centers.
+    pub fn from_cells(
+        values: &[f64],
+        num_rows: usize,
+        num_columns: usize,
+        extent: &PlotBounds,
+        first_row_at_bottom: bool,
+    ) -> Self {
+        let step = [
+            extent.width() / num_columns as f64,
+            extent.height() / num_rows as f64,
+        ];
+        let values = (0..num_rows)
+            .flat_map(|row_from_bottom| {
+                let row = if first_row_at_bottom {
+                    row_from_bottom
+                } else {
+                    num_rows - 1 - row_from_bottom
+                };
+                (0..num_columns).map(move |column| values.get(row * num_columns + column).copied())
+            })
+            .map(|value| value.unwrap_or(f64::NAN))
+            .collect();
+        Self {
+            values,
+            num_rows,
+            num_columns,
+            origin: [
+                extent.min()[0] + 0.5 * step[0],
+                extent.min()[1] + 0.5 * step[1],
+            ],
+            step,
+        }
+    }
+
+    /// `function(x, y)` sampled at `num_columns × num_rows` points spread evenly over `bounds`.
+    pub fn from_fn(
+        function: impl Fn(f64, f64) -> f64,
+        bounds: &PlotBounds,
+        num_columns: usize,
+        num_rows: usize,
+    ) -> Self {
+        let (num_columns, num_rows) = (num_columns.max(2), num_rows.max(2));
+        let step = [
+            bounds.width() / (num_columns - 1) as f64,
+            bounds.height() / (num_rows - 1) as f64,
+        ];
+        let origin = bounds.min();
+        let values = (0..num_rows)
+            .flat_map(|row| (0..num_columns).map(move |column| (column, row)))
+            .map(|(column, row)| {
+                function(
+                    origin[0] + column as f64 * step[0],
+                    origin[1] + row as f64 * step[1],
+                )
+            })
+            .collect();
+        Self {
+            values,
+            num_rows,
+            num_columns,
+            origin,
+            step,
+        }
+    }
+
+    /// The smallest and largest finite value.
+    pub fn value_range(&self) -> Option<(f64, f64)> {
+        let (min, max) = self
+            .values
+            .iter()
+            .filter(|value| value.is_finite())
+            .fold((f64::INFINITY, -f64::INFINITY), |(min, max), &value| {
+                (min.min(value), max.max(value))
+            });
+        (min <= max).then_some((min, max))
+    }
+
+    fn value(&self, column: usize, row: usize) -> f64 {
+        self.values[row * self.num_columns + column]
+    }
+
+    fn position(&self, column: usize, row: usize) -> PlotPoint {
+        PlotPoint::new(
+            self.origin[0] + column as f64 * self.step[0],
+            self.origin[1] + row as f64 * self.step[1],
+        )
+    }
+
+    /// The corners of the cell with `(column, row)` at the bottom left, counter-clockwise.
+    fn cell_corners(&self, column: usize, row: usize) -> [(PlotPoint, f64); 4] {
+        [
+            (column, row),
+            (column + 1, row),
+            (column + 1, row + 1),
+            (column, row + 1),
+        ]
+        .map(|(column, row)| (self.position(column, row), self.value(column, row)))
+    }
+
+    /// Where `level` crosses `edge`.
+    fn crossing(&self, edge: Edge, level: f64) -> PlotPoint {
+        let (column, row) = if edge.vertical {
+            (edge.column, edge.row + 1)
+        } else {
+            (edge.column + 1, edge.row)
+        };
+        interpolate(
+            (
+                self.position(edge.column, edge.row),
+                self.value(edge.column, edge.row),
+            ),
+            (self.position(column, row), self.value(column, row)),
+            level,
+        )
+    }
+
+    /// The lines where the field equals `level`, joined into polylines.
+    pub fn isolines(&self, level: f64) -> Vec<Vec<PlotPoint>> {
+        let mut segments = Vec::new();
+        for row in 0..self.num_rows.saturating_sub(1) {
+            for column in 0..self.num_columns.saturating_sub(1) {
+                self.cell_segments(column, row, level, &mut segments);
+            }
+        }
+
+        join_segments(&segments)
+            .into_iter()
+            .map(|edges| {
+                edges
+                    .into_iter()
+                    .map(|edge| self.crossing(edge, level))
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// The segments of the isoline at `level` through one cell, as pairs of crossed edges.
+    fn cell_segments(&self, column: usize, row: usize, level: f64, out: &mut Vec<(Edge, Edge)>) {
+        let corners = [
+            self.value(column, row),
+            self.value(column + 1, row),
+            self.value(column + 1, row + 1),
+            self.value(column, row + 1),
+        ];
+        if corners.iter().any(|value| value.is_nan()) {
+            return;
+        }
+        let edge = |column, row, vertical| Edge {
+            column,
+            row,
+            vertical,
+        };
+        let bottom = edge(column, row, false);
+        let right = edge(column + 1, row, true);
+        let top = edge(column, row + 1, false);
+        let left = edge(column, row, true);
+
+        let above = corners.map(|value| level <= value);
+        let case = above
+            .iter()
+            .enumerate()
+            .fold(0, |case, (bit, &above)| case | (usize::from(above) << bit));
+        match case {
+            0 | 15 => {}
+            1 | 14 => out.push((left, bottom)),
+            2 | 13 => out.push((bottom, right)),
+            3 | 12 => out.push((left, right)),
+            4 | 11 => out.push((right, top)),
+            6 | 9 => out.push((bottom, top)),
+            7 | 8 => out.push((left, top)),
+            _ => {
+                // A saddle: the average decides whether the center is connected to the corners
+                // at the bottom left and top right, or to the other two.
+                let center_above = level <= corners.iter().sum::<f64>() / 4.0;
+                if center_above == above[0] {
+                    out.push((bottom, right));
+                    out.push((top, left));
+                } else {
+                    out.push((left, bottom));
+                    out.push((right, top));
+                }
+            }
+        }
+    }
+
+    /// The parts of the field with a value in `min..=max`, as convex polygons.
+    ///
+    /// Use infinite values for bands that are open at one end.
+    pub fn band(&self, min: f64, max: f64) -> Vec<Vec<PlotPoint>> {
+        let mut polygons = Vec::new();
+        for row in 0..self.num_rows.saturating_sub(1) {
+            for column in 0..self.num_columns.saturating_sub(1) {
+                let corners = self.cell_corners(column, row);
+                if corners.iter().any(|(_, value)| value.is_nan()) {
+                    continue;
+                }
+                let mut polygon = corners.to_vec();
+                if min.is_finite() {
+                    polygon = clip(&polygon, min, |value| min <= value);
+                }
+                if max.is_finite() {
+                    polygon = clip(&polygon, max, |value| value <= max);
+                }
+                if 3 <= polygon.len() {
+                    polygons.push(polygon.into_iter().map(|(point, _)| point).collect());
+                }
+            }
+        }
+        polygons
+    }
+}
+
+/// The point between `a` and `b` where the linearly interpolated value is `level`.
+fn interpolate(
+    (a, a_value): (PlotPoint, f64),
+    (b, b_value): (PlotPoint, f64),
+    level: f64,
+) -> PlotPoint {
+    let t = if a_value == b_value {
+        0.5
+    } else {
+        (level - a_value) / (b_value - a_value)
+    };
+    PlotPoint::new(a.x + t * (b.x - a.x), a.y + t * (b.y - a.y))
+}
+
+/// Keep the part of `polygon` where `keep` holds, cutting its edges where the value is `level`.
+fn clip(
+    polygon: &[(PlotPoint, f64)],
+    level: f64,
+    keep: impl Fn(f64) -> bool,
+) -> Vec<(PlotPoint, f64)> {
+    let mut clipped = Vec::with_capacity(polygon.len() + 2);
+    for (i, &current) in polygon.iter().enumerate() {
+        let next = polygon[(i + 1) % polygon.len()];
+        if keep(current.1) {
+            clipped.push(current);
+        }
+        if keep(current.1) != keep(next.1) {
+            clipped.push((interpolate(current, next, level), level));
+        }
+    }
+    clipped
+}
+
+/// Join segments that share an edge into chains of edges.
+fn join_segments(segments: &[(Edge, Edge)]) -> Vec<Vec<Edge>> {
+    let mut segments_at_edge: HashMap<Edge, Vec<usize>> = HashMap::default();
+    for (index, (a, b)) in segments.iter().enumerate() {
+        segments_at_edge.entry(*a).or_default().push(index);
+        segments_at_edge.entry(*b).or_default().push(index);
+    }
+
+    let mut used = vec![false; segments.len()];
+    let next_edge = |edge: Edge, used: &mut [bool]| {
+        let index = *segments_at_edge
+            .get(&edge)?
+            .iter()
+            .find(|&&index| !used[index])?;
+        used[index] = true;
+        let (a, b) = segments[index];
+        Some(if a == edge { b } else { a })
+    };
+
+    let mut chains = Vec::new();
+    for start in 0..segments.len() {
+        if used[start] {
+            continue;
+        }
+        used[start] = true;
+        let (a, b) = segments[start];
+
+        let mut forward = vec![a, b];
+        while let Some(edge) = next_edge(*forward.last().unwrap_or(&b), &mut used) {
+            forward.push(edge);
+        }
+        let mut backward = Vec::new();
+        while let Some(edge) = next_edge(*backward.last().unwrap_or(&a), &mut used) {
+            backward.push(edge);
+        }
+
+        backward.reverse();
+        backward.extend(forward);
+        chains.push(backward);
+    }
+    chains
+}
+
+/// Cut a gap of `width` out of the middle of a polyline on screen, to put a label in.
+///
+/// Returns the center and direction of the gap and the two remaining pieces,
+/// or `None` if the line is too short to fit the label.
+pub(crate) fn cut_label_gap(
+    points: &[egui::Pos2],
+    width: f32,
+) -> Option<(egui::Pos2, egui::Vec2, [Vec<egui::Pos2>; 2])> {
+    let lengths: Vec<f32> = points
+        .windows(2)
+        .map(|pair| pair[0].distance(pair[1]))
+        .collect();
+    let total: f32 = lengths.iter().sum();
+    if total < 3.0 * width {
+        return None;
+    }
+    let (start, end) = (0.5 * (total - width), 0.5 * (total + width));
+
+    let mut before = vec![points[0]];
+    let mut after = Vec::new();
+    let mut center = None;
+    let mut traveled = 0.0;
+    for (pair, &length) in points.windows(2).zip(&lengths) {
+        let at = |distance: f32| pair[0].lerp(pair[1], (distance - traveled) / length);
+        let next = traveled + length;
+        if traveled < start && start <= next {
+            before.push(at(start));
+        }
+        if traveled < 0.5 * total && 0.5 * total <= next {
+            center = Some((at(0.5 * total), pair[1] - pair[0]));
+        }
+        if traveled < end && end <= next {
+            after.push(at(end));
+        }
+        if next < start {
+            before.push(pair[1]);
+        } else if end < next {
+            after.push(pair[1]);
+        }
+        traveled = next;
+    }
+    let (center, direction) = center?;
+    Some((center, direction.normalized(), [before, after]))
+}
+
+#[test]
+fn test_marching_squares() {
+    // A cone around the center of a 5×5 grid:
+    let cone = SampledField::from_fn(
+        |x: f64, y| x.hypot(y),
+        &PlotBounds::from_min_max([-2.0, -2.0], [2.0, 2.0]),
+        5,
+        5,
+    );
+    assert_eq!(cone.value_range().map(|(min, _)| min), Some(0.0));
+
+    // The circle of radius 1.5 is a single closed line:
+    let lines = cone.isolines(1.5);
+    assert_eq!(lines.len(), 1);
+    let circle = &lines[0];
+    assert_eq!(circle.first(), circle.last());
+    for point in circle {
+        let radius = point.x.hypot(point.y);
+        assert!((1.0..=1.5).contains(&radius), "{point:?}");
+    }
+
+    // Everything below 1.5 is inside the circle:
+    let inside = cone.band(f64::NEG_INFINITY, 1.5);
+    assert!(!inside.is_empty());
+    for point in inside.iter().flatten() {
+        assert!(point.x.abs() <= 1.5 && point.y.abs() <= 1.5, "{point:?}");
+    }
+    assert!(cone.band(10.0, f64::INFINITY).is_empty());
+
+    let cells = SampledField::from_cells(
+        &[1.0, 2.0, 3.0, 4.0],
+        2,
+        2,
+        &PlotBounds::from_min_max([0.0, 0.0], [2.0, 2.0]),
+        false,
+    );
+    assert_eq!(cells.position(0, 0), PlotPoint::new(0.5, 0.5));
+    assert_eq!(cells.value(0, 0), 3.0);
+    assert_eq!(cells.value(1, 1), 2.0);
+}
@@ -0,0 +1,483 @@
+use std::ops::RangeInclusive;
+
+use egui::{Color32, Id, NumExt as _, Pos2, Shape, Stroke, Ui};
+use emath::Float as _;
+
+use super::{
+    Bar, ClosestElem, Cursor, LabelFormatter, LineStyle, Orientation, PlotBounds, PlotConfig,
+    PlotGeometry, PlotItem, PlotItemBase, PlotTransform, RectElement as _, add_rulers_and_text,
+    builder_methods_for_base, find_closest_rect,
+};
+use crate::format_number;
+
+/// More bins than this are never created automatically.
+const MAX_AUTO_BINS: usize = 10_000;
+
+/// How the samples of a [`Histogram`] are divided into bins.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub enum HistogramBins {
+    /// This many bins of equal width, from the smallest sample to the largest.
+    Count(usize),
+
+    /// Bins of this width, aligned to multiples of it.
+    ///
+    /// If that would take more than ten thousand bins, the width is multiplied by the
+    /// smallest whole number that makes it fit.
+    Width(f64),
+
+    /// Sturges' rule: `log2(n) + 1` bins of equal width. Works well for small,
+    /// roughly normal samples.
+    #[default]
+    Sturges,
+
+    /// The Freedman–Diaconis rule: the bin width is `2 IQR / n^(1/3)`, which is robust
+    /// against outliers. Falls back to [`Self::Sturges`] if the samples have no spread.
+    FreedmanDiaconis,
+
+    /// Bins between these edges. Samples outside of the edges are not counted.
+    ///
+    /// The edges are sorted, and duplicate and non-finite edges are left out.
+    Edges(Vec<f64>),
+}
+
+impl HistogramBins {
+    /// The edges of the bins for the finite, sorted `samples`.
+    fn edges(&self, samples: &[f64]) -> Vec<f64> {
+        if let Self::Edges(edges) = self {
+            let mut edges: Vec<f64> = edges
+                .iter()
+                .copied()
+                .filter(|edge| edge.is_finite())
+                .collect();
+            edges.sort_by(f64::total_cmp);
+            edges.dedup();
+            return edges;
+        }
+        let (Some(&min), Some(&max)) = (samples.first(), samples.last()) else {
+            return Vec::new();
+        };
+        let (min, max) = if min == max {
+            (min - 0.5, max + 0.5)
+        } else {
+            (min, max)
+        };
+
+        let sturges = || (samples.len() as f64).log2().ceil() as usize + 1;
+        let num_bins = match self {
+            Self::Width(width) if ((max - min) / width).is_finite() && 0.0 < *width => {
+                // Wider bins rather than too many, still aligned to multiples of the width:
+                let factor = (((max - min) / width + 1.0) / MAX_AUTO_BINS as f64).ceil();
+                let width = width * factor.at_least(1.0);
+                let start = (min / width).floor() * width;
+                let num_bins = ((max - start) / width).ceil().max(1.0) as usize;
+                return (0..=num_bins.at_most(MAX_AUTO_BINS))
+                    .map(|i| start + i as f64 * width)
+                    .collect();
+            }
+            Self::Count(num_bins) => *num_bins,
+            Self::FreedmanDiaconis => {
+                let iqr = quantile(samples, 0.75) - quantile(samples, 0.25);
+                let width = 2.0 * iqr / (samples.len() as f64).cbrt();
+                if width > 0.0 {
+                    ((max - min) / width).ceil() as usize
+                } else {
+                    sturges()
+                }
+            }
+            Self::Width(_) | Self::Sturges | Self::Edges(_) => sturges(),
+        };
+        let num_bins = num_bins.clamp(1, MAX_AUTO_BINS);
+        (0..=num_bins)
+            .map(|i| {
+                // Interpolated so that `max - min` can't overflow:
+                let t = i as f64 / num_bins as f64;
+                min * (1.0 - t) + max * t
+            })
+            .collect()
+    }
+}
+
+/// The value below which a fraction `q` of the sorted `samples` lie, interpolating linearly.
+pub(super) fn quantile(samples: &[f64], q: f64) -> f64 {
+    let position = q * (samples.len() - 1) as f64;
+    let (below, above) = (position.floor() as usize, position.ceil() as usize);
+    samples[below] + (position - below as f64) * (samples[above] - samples[below])
+}
+
+/// How many samples fall in each bin. The last bin includes its upper edge.
+fn count_samples(samples: &[f64], edges: &[f64]) -> Vec<usize> {
+    let num_bins = edges.len().saturating_sub(1);
+    let mut counts = vec![0; num_bins];
+    let (Some(&first), Some(&last)) = (edges.first(), edges.last()) else {
+        return counts;
+    };
+    for &sample in samples {
+        if sample < first || last < sample || num_bins == 0 {
+            continue;
+        }
+        let Some(bin) = edges.partition_point(|&edge| edge <= sample).checked_sub(1) else {
+            continue;
+        };
+        counts[bin.at_most(num_bins - 1)] += 1;
+    }
+    counts
+}
+
+/// A histogram of raw samples, drawn as a bar per bin.
+///
+/// Several histograms can overlap, or be stacked with [`Self::stack_on`].
+/// Give them the same bins with [`HistogramBins::Edges`] and [`Self::bin_edges`] so
+/// their bars line up.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// use egui_plot::{Histogram, HistogramBins, Plot};
+///
+/// let samples = vec![1.2, 2.3, 2.4, 2.9, 3.1, 3.3, 4.8];
+/// let histogram = Histogram::new("Response time", samples)
+///     .bins(HistogramBins::Width(1.0))
+///     .density(true);
+/// Plot::new("histogram").show(ui, |plot_ui| plot_ui.histogram(histogram));
+/// # });
+/// ```
+pub struct Histogram {
+    base: PlotItemBase,
+    samples: Vec<f64>,
+    bins: HistogramBins,
+    density: bool,
+    cumulative: bool,
+    step: bool,
+    orientation: Orientation,
+    pub(crate) color: Color32,
+    stacked_on: Vec<f64>,
+
+    edges: Vec<f64>,
+    counts: Vec<usize>,
+    pub(crate) bars: Vec<Bar>,
+}
+
+impl Histogram {
+    /// A histogram of `samples`. Samples that are not finite are ignored.
+    pub fn new(name: impl Into<String>, samples: impl Into<Vec<f64>>) -> Self {
+        let mut samples = samples.into();
+        samples.retain(|sample| sample.is_finite());
+        samples.sort_by(|a, b| a.total_cmp(b));
+        let mut histogram = Self {
+            base: PlotItemBase::new(name.into()),
+            samples,
+            bins: HistogramBins::default(),
+            density: false,
+            cumulative: false,
+            step: false,
+            orientation: Orientation::Vertical,
+            color: Color32::TRANSPARENT,
+            stacked_on: Vec::new(),
+            edges: Vec::new(),
+            counts: Vec::new(),
+            bars: Vec::new(),
+        };
+        histogram.update_bins();
+        histogram
+    }
+
+    /// How to divide the samples into bins. Default: [`HistogramBins::Sturges`].
+    #[inline]
+    pub fn bins(mut self, bins: HistogramBins) -> Self {
+        self.bins = bins;
+        self.update_bins();
+        self
+    }
+
+    /// Show the density instead of the count, so the area of all bars adds up to one.
+    ///
+    /// Samples outside of the bins (see [`HistogramBins::Edges`]) don't count towards the total.
+    #[inline]
+    pub fn density(mut self, density: bool) -> Self {
+        self.density = density;
+        self.update_bars();
+        self
+    }
+
+    /// Let each bar include all bins before it, so the last bar shows the total.
+    ///
+    /// Together with [`Self::density`] this is the empirical cumulative distribution,
+    /// ending at one.
+    #[inline]
+    pub fn cumulative(mut self, cumulative: bool) -> Self {
+        self.cumulative = cumulative;
+        self.update_bars();
+        self
+    }
+
+    /// Only draw the outline of the bars, as one line. Default: `false`.
+    #[inline]
+    pub fn step(mut self, step: bool) -> Self {
+        self.step = step;
+        self.update_bars();
+        self
+    }
+
+    /// Put the bins along the y axis and the counts along the x axis.
+    #[inline]
+    pub fn horizontal(mut self) -> Self {
+        self.orientation = Orientation::Horizontal;
+        self.update_bars();
+        self
+    }
+
+    /// Put the bins along the x axis and the counts along the y axis. This is the default.
+    #[inline]
+    pub fn vertical(mut self) -> Self {
+        self.orientation = Orientation::Vertical;
+        self.update_bars();
+        self
+    }
+
+    /// Color of the bars. This is the color that shows up in the legend.
+    /// Default is `Color32::TRANSPARENT` which means a color will be auto-assigned.
+    #[inline]
+    pub fn color(mut self, color: impl Into<Color32>) -> Self {
+        self.color = color.into();
+        self.update_bars();
+        self
+    }
+
+    /// Stack the bars on top of those of other histograms with the same bins.
+    #[inline]
+    pub fn stack_on(mut self, others: &[&Self]) -> Self {
+        self.stacked_on = (0..self.counts.len())
+            .map(|index| {
+                others
+                    .iter()
+                    .filter_map(|other| other.bars.get(index).map(|bar| bar.upper()))
+                    .max_by_key(|value| value.ord())
+                    .unwrap_or(0.0)
+            })
+            .collect();
+        self.update_bars();
+        self
+    }
+
+    /// The edges of the bins, e.g. to give other histograms the same bins.
+    pub fn bin_edges(&self) -> &[f64] {
+        &self.edges
+    }
+
+    /// The number of samples in each bin.
+    pub fn counts(&self) -> &[usize] {
+        &self.counts
+    }
+
+    builder_methods_for_base!();
+
+    fn update_bins(&mut self) {
+        self.edges = self.bins.edges(&self.samples);
+        self.counts = count_samples(&self.samples, &self.edges);
+        self.update_bars();
+    }
+
+    fn update_bars(&mut self) {
+        // Like numpy, the density is relative to the samples within the bins:
+        let total = self.counts.iter().sum::<usize>().at_least(1) as f64;
+        let mut cumulative = 0.0;
+        self.bars = self
+            .counts
+            .iter()
+            .enumerate()
+            .map(|(index, &count)| {
+                let (start, end) = (self.edges[index], self.edges[index + 1]);
+                let width = end - start;
+                let mut value = count as f64;
+                if self.density {
+                    // In a cumulative histogram the density is summed over the bins
+                    // without their width, so it ends at one.
+                    value /= if self.cumulative {
+                        total
+                    } else {
+                        total * width
+                    };
+                }
+                if self.cumulative {
+                    cumulative += value;
+                    value = cumulative;
+                }
+                let mut bar = Bar::new(0.5 * (start + end), value)
+                    .width(width)
+                    .stroke(Stroke::new(1.0, self.color))
+                    .fill(if self.step {
+                        Color32::TRANSPARENT
+                    } else {
+                        self.color.linear_multiply(0.2)
+                    });
+                bar.orientation = self.orientation;
+                if let Some(&offset) = self.stacked_on.get(index) {
+                    bar = bar.base_offset(offset);
+                }
+                bar
+            })
+            .collect();
+    }
+
+    /// The outline of all bars, from the base of the first one to the base of the last one.
+    fn step_line(&self, transform: &PlotTransform) -> Vec<Pos2> {
+        let mut line = Vec::with_capacity(2 * self.bars.len() + 2);
+        for (index, bar) in self.bars.iter().enumerate() {
+            let (start, end) = (self.edges[index], self.edges[index + 1]);
+            if index == 0 {
+                line.push(bar.point_at(start, bar.base_offset.unwrap_or(0.0)));
+            }
+            let top = bar.base_offset.unwrap_or(0.0) + bar.value;
+            line.push(bar.point_at(start, top));
+            line.push(bar.point_at(end, top));
+            if index + 1 == self.bars.len() {
+                line.push(bar.point_at(end, bar.base_offset.unwrap_or(0.0)));
+            }
+        }
+        line.iter()
+            .map(|point| transform.position_from_point(point))
+            .collect()
+    }
+}
+
+impl PlotItem for Histogram {
+    fn shapes(&self, _ui: &Ui, transform: &PlotTransform, shapes: &mut Vec<Shape>) {
+        if self.step {
+            LineStyle::Solid.style_line(
+                self.step_line(transform),
+                Stroke::new(1.5, self.color),
+                self.base.highlight,
+                shapes,
+            );
+        } else {
+            for bar in &self.bars {
+                bar.add_shapes(transform, self.base.highlight, shapes);
+            }
+        }
+    }
+
+    fn initialize(&mut self, _x_range: RangeInclusive<f64>) {}
+
+    fn color(&self) -> Color32 {
+        self.color
+    }
+
+    fn geometry(&self) -> PlotGeometry<'_> {
+        PlotGeometry::Rects
+    }
+
+    fn bounds(&self) -> PlotBounds {
+        let mut bounds = PlotBounds::NOTHING;
+        for bar in &self.bars {
+            bounds.merge(&bar.bounds());
+        }
+        bounds
+    }
+
+    fn find_closest(&self, point: Pos2, transform: &PlotTransform) -> Option<ClosestElem> {
+        find_closest_rect(&self.bars, point, transform)
+    }
+
+    fn on_hover(
+        &self,
+        _plot_area_response: &egui::Response,
+        elem: ClosestElem,
+        shapes: &mut Vec<Shape>,
+        cursors: &mut Vec<Cursor>,
+        plot: &PlotConfig<'_>,
+        _: &LabelFormatter<'_>,
+    ) {
+        let bar = &self.bars[elem.index];
+        bar.add_shapes(plot.transform, true, shapes);
+
+        let scale = plot.transform.dvalue_dpos();
+        let [argument_scale, value_scale] = match self.orientation {
+            Orientation::Horizontal => [scale[1], scale[0]],
+            Orientation::Vertical => scale,
+        };
+        let decimals =
+            |scale: f64| ((-scale.abs().log10()).ceil().at_least(0.0) as usize).at_most(6);
+        let (start, end) = (self.edges[elem.index], self.edges[elem.index + 1]);
+        let closing_bracket = if elem.index + 1 == self.counts.len() {
+            ']'
+        } else {
+            ')'
+        };
+        let prefix = if self.name().is_empty() {
+            String::new()
+        } else {
+            format!("{}\n", self.name())
+        };
+        let mut text = format!(
+            "{prefix}[{}, {}{closing_bracket}\ncount = {}",
+            format_number(start, decimals(argument_scale)),
+            format_number(end, decimals(argument_scale)),
+            self.counts[elem.index],
+        );
+        if self.density || self.cumulative {
+            let label = if self.density && !self.cumulative {
+                "density"
+            } else {
+                "cumulative"
+            };
+            text.push_str(&format!(
+                "\n{label} = {}",
+                format_number(bar.value, decimals(value_scale) + 1)
+            ));
+        }
+        add_rulers_and_text(bar, plot, Some(text), shapes, cursors);
+    }
+
+    fn base(&self) -> &PlotItemBase {
+        &self.base
+    }
+
+    fn base_mut(&mut self) -> &mut PlotItemBase {
+        &mut self.base
+    }
+}
+
+#[test]
+fn test_histogram_bins() {
+    let samples = vec![0.0, 0.5, 1.0, 1.5, 2.0, 2.0, 9.0, f64::NAN];
+
+    let histogram = Histogram::new("", samples.clone()).bins(HistogramBins::Width(1.0));
+    assert_eq!(
+        histogram.bin_edges(),
+        [0.0, 1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0]
+    );
+    assert_eq!(histogram.counts(), [2, 2, 2, 0, 0, 0, 0, 0, 1]);
+
+    // Sturges: log2(7) + 1 = 4 bins, and the largest sample is in the last one:
+    let histogram = Histogram::new("", samples.clone());
+    assert_eq!(histogram.counts(), [6, 0, 0, 1]);
+
+    // The sample at 9.0 is outside of the edges, so the other six make up all of the area:
+    let histogram = Histogram::new("", samples.clone())
+        .bins(HistogramBins::Edges(vec![0.0, 1.0, 3.0]))
+        .density(true);
+    assert_eq!(histogram.counts(), [2, 4]);
+    let area: f64 = histogram
+        .bars
+        .iter()
+        .map(|bar| bar.value * bar.bar_width)
+        .sum();
+    assert!((area - 1.0).abs() < 1e-12);
+
+    let histogram =
+        Histogram::new("", samples).bins(HistogramBins::Edges(vec![f64::NAN, 3.0, 0.0, 1.0, 1.0]));
+    assert_eq!(histogram.bin_edges(), [0.0, 1.0, 3.0]);
+    assert_eq!(histogram.counts(), [2, 4]);
+
+    // Too many bins of the given width are widened, without dropping samples:
+    let histogram = Histogram::new("", vec![0.0, 1e6]).bins(HistogramBins::Width(1.0));
+    assert_eq!(histogram.counts().iter().sum::<usize>(), 2);
+    assert!(histogram.counts().len() <= MAX_AUTO_BINS);
+    assert_eq!(histogram.bin_edges()[1], 101.0);
+
+    // Samples so far apart that their distance overflows:
+    for bins in [HistogramBins::Sturges, HistogramBins::Width(1.0)] {
+        let histogram = Histogram::new("", vec![-f64::MAX, f64::MAX]).bins(bins);
+        assert!(histogram.bin_edges().iter().all(|edge| edge.is_finite()));
+        assert_eq!(histogram.counts().iter().sum::<usize>(), 2);
+    }
+}
@@ -11,30 +11,47 @@ use egui::{
     pos2, vec2,
 };
 
+use contour::{SampledField, cut_label_gap};
 use emath::Float as _;
 use rect_elem::{RectElement, highlighted_color};
 
-use super::{Cursor, LabelFormatter, PlotBounds, PlotTransform};
+use super::{Colorbar, Colormap, Cursor, LabelFormatter, PlotBounds, PlotTransform};
 
 pub use bar::Bar;
 pub use box_elem::{BoxElem, BoxSpread};
+pub use candle::{Candle, CandleStyle};
+pub use histogram::{Histogram, HistogramBins};
+pub use stacked::{StackOffset, StackedAreas};
 pub use values::{
     ClosestElem, LineStyle, MarkerShape, Orientation, PlotGeometry, PlotPoint, PlotPoints,
+    PointErrors, StepMode,
 };
+pub use violin::{ViolinElem, ViolinPlot, ViolinSide};
+
+pub(crate) use values::points_bounds_in_range;
 
 mod bar;
 mod box_elem;
+mod candle;
+mod contour;
+mod histogram;
 mod rect_elem;
+mod stacked;
 mod values;
+mod violin;
 
 const DEFAULT_FILL_ALPHA: f32 = 0.05;
 
+/// Width of the caps at the ends of error bars, in ui points.
+const DEFAULT_ERROR_CAP_WIDTH: f32 = 6.0;
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct PlotItemBase {
     name: String,
     id: Id,
     highlight: bool,
     allow_hover: bool,
+    secondary_y_axis: Option<String>,
 }
 
 impl PlotItemBase {
@@ -45,6 +62,7 @@ impl PlotItemBase {
             id,
             highlight: false,
             allow_hover: true,
+            secondary_y_axis: None,
         }
     }
 }
@@ -85,9 +103,21 @@ macro_rules! builder_methods_for_base {
             self.base_mut().id = id.into();
             self
         }
+
+        /// Plot this item against the named secondary y axis, which has its own bounds.
+        ///
+        /// See [`crate::AxisHints::secondary_y_axis`].
+        #[inline]
+        pub fn secondary_y_axis(mut self, name: impl Into<String>) -> Self {
+            self.base_mut().secondary_y_axis = Some(name.into());
+            self
+        }
     };
 }
 
+// So it can be used by items in submodules, which are declared above the macro.
+use builder_methods_for_base;
+
 /// Container to pass-through several parameters related to plot visualization
 pub struct PlotConfig<'a> {
     pub ui: &'a Ui,
@@ -134,19 +164,34 @@ pub trait PlotItem {
         self.base().id
     }
 
+    /// The name of the secondary y axis this item is plotted against, if any.
+    fn secondary_y_axis(&self) -> Option<&str> {
+        self.base().secondary_y_axis.as_deref()
+    }
+
+    /// The `[lower, upper]` errors along x and y of the point at `index` of the
+    /// [`PlotGeometry::Points`], shown in the hover label.
+    fn point_errors(&self, _index: usize) -> [Option<[f64; 2]>; 2] {
+        [None, None]
+    }
+
+    /// The colormap and value range this item colors its values with,
+    /// shown by a [`Colorbar::for_item`]. `visible` are the bounds the plot shows.
+    fn color_mapping(&self, _visible: &PlotBounds) -> Option<(Colormap, RangeInclusive<f64>)> {
+        None
+    }
+
+    /// Whether the [`PlotGeometry::Points`] are known to be sorted by x,
+    /// so the visible ones can be found with a binary search.
+    fn sorted_by_x(&self) -> bool {
+        false
+    }
+
     fn find_closest(&self, point: Pos2, transform: &PlotTransform) -> Option<ClosestElem> {
         match self.geometry() {
             PlotGeometry::None => None,
 
-            PlotGeometry::Points(points) => points
-                .iter()
-                .enumerate()
-                .map(|(index, value)| {
-                    let pos = transform.position_from_point(value);
-                    let dist_sq = point.distance_sq(pos);
-                    ClosestElem { index, dist_sq }
-                })
-                .min_by_key(|e| e.dist_sq.ord()),
+            PlotGeometry::Points(points) => find_closest_point(points, point, transform),
 
             PlotGeometry::Rects => {
                 panic!("If the PlotItem is made of rects, it should implement find_closest()")
@@ -187,6 +232,7 @@ pub trait PlotItem {
         rulers_and_tooltip_at_value(
             plot_area_response,
             value,
+            self.point_errors(elem.index),
             self.name(),
             plot,
             cursors,
@@ -391,6 +437,9 @@ pub struct Line<'a> {
     pub(super) fill: Option<f32>,
     pub(super) fill_alpha: f32,
     pub(super) style: LineStyle,
+    step: Option<StepMode>,
+    errors: PointErrors,
+    sorted_by_x: bool,
 }
 
 impl<'a> Line<'a> {
@@ -402,6 +451,9 @@ impl<'a> Line<'a> {
             fill: None,
             fill_alpha: DEFAULT_FILL_ALPHA,
             style: LineStyle::Solid,
+            step: None,
+            errors: PointErrors::default(),
+            sorted_by_x: false,
         }
     }
 
@@ -447,6 +499,34 @@ impl<'a> Line<'a> {
         self
     }
 
+    /// Draw the line as steps, holding each value until the next point changes it.
+    ///
+    /// This also applies to the [`Self::fill`] and to hovering, which still reports the
+    /// original points. Default: straight lines between the points.
+    #[inline]
+    pub fn step(mut self, mode: StepMode) -> Self {
+        self.step = Some(mode);
+        self
+    }
+
+    /// Promise that the points are sorted by x, as they usually are for time series.
+    ///
+    /// This lets [`crate::Plot::auto_fit_visible`] and [`crate::Plot::follow_x`] find the
+    /// visible points with a binary search instead of checking all of them.
+    /// Points generated from a function are always sorted. Default: `false`.
+    #[inline]
+    pub fn sorted_by_x(mut self, sorted: bool) -> Self {
+        self.sorted_by_x = sorted;
+        self
+    }
+
+    /// Draw error bars at the points of the line, in the color of the line.
+    #[inline]
+    pub fn error_bars(mut self, errors: PointErrors) -> Self {
+        self.errors = errors;
+        self
+    }
+
     builder_methods_for_base!();
 }
 
@@ -469,11 +549,18 @@ impl PlotItem for Line<'_> {
         } = self;
         let mut fill = *fill;
 
-        let values_tf: Vec<_> = series
-            .points()
-            .iter()
-            .map(|v| transform.position_from_point(v))
-            .collect();
+        let values_tf: Vec<_> = match self.step {
+            Some(mode) => mode
+                .corners(series.points())
+                .iter()
+                .map(|(corner, _)| transform.position_from_point(corner))
+                .collect(),
+            None => series
+                .points()
+                .iter()
+                .map(|v| transform.position_from_point(v))
+                .collect(),
+        };
         let n_values = values_tf.len();
 
         // Fill the area between the line and a reference line, if required.
@@ -515,10 +602,18 @@ impl PlotItem for Line<'_> {
             mesh.colored_vertex(pos2(last.x, y), fill_color);
             shapes.push(Shape::Mesh(std::sync::Arc::new(mesh)));
         }
+        self.errors.shapes(
+            series.points(),
+            transform,
+            Stroke::new(1.0, stroke.color),
+            DEFAULT_ERROR_CAP_WIDTH,
+            shapes,
+        );
         style.style_line(values_tf, *stroke, base.highlight, shapes);
     }
 
     fn initialize(&mut self, x_range: RangeInclusive<f64>) {
+        self.sorted_by_x |= matches!(self.series, PlotPoints::Generator(_));
         self.series.generate_points(x_range);
     }
 
@@ -539,8 +634,338 @@ impl PlotItem for Line<'_> {
     }
 
     fn bounds(&self) -> PlotBounds {
-        self.series.bounds()
+        if self.errors.is_empty() {
+            self.series.bounds()
+        } else {
+            self.errors.bounds(self.series.points())
+        }
+    }
+
+    fn point_errors(&self, index: usize) -> [Option<[f64; 2]>; 2] {
+        self.errors.at(index)
+    }
+
+    fn sorted_by_x(&self) -> bool {
+        self.sorted_by_x
+    }
+
+    fn find_closest(&self, point: Pos2, transform: &PlotTransform) -> Option<ClosestElem> {
+        let Some(mode) = self.step else {
+            return find_closest_point(self.series.points(), point, transform);
+        };
+        let corners: Vec<(Pos2, usize)> = mode
+            .corners(self.series.points())
+            .iter()
+            .map(|(corner, index)| (transform.position_from_point(corner), *index))
+            .collect();
+        if corners.len() < 2 {
+            return find_closest_point(self.series.points(), point, transform);
+        }
+
+        // The closest part of the staircase belongs to the point whose value it shows:
+        corners
+            .windows(2)
+            .map(|pair| {
+                let [(start, start_index), (end, end_index)] = [pair[0], pair[1]];
+                let direction = end - start;
+                let t = if direction.length_sq() > 0.0 {
+                    ((point - start).dot(direction) / direction.length_sq()).clamp(0.0, 1.0)
+                } else {
+                    0.0
+                };
+                ClosestElem {
+                    index: if t < 0.5 { start_index } else { end_index },
+                    dist_sq: point.distance_sq(start + t * direction),
+                }
+            })
+            .min_by_key(|elem| elem.dist_sq.ord())
+    }
+}
+
+/// The area between two series, e.g. a confidence band or a min/max envelope.
+///
+/// The series can have different x values and can cross each other. Where the first series
+/// is above the second one the area gets the [`Self::color`], and where it is below,
+/// the [`Self::below_color`]. Both series should be sorted by x; only the x range they share
+/// is filled.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// use egui_plot::{FillBetween, Plot};
+///
+/// let upper = vec![[0.0, 1.2], [1.0, 1.5], [2.0, 1.1]];
+/// let lower = vec![[0.0, 0.8], [0.5, 0.9], [2.0, 0.7]];
+/// Plot::new("band").show(ui, |plot_ui| {
+///     plot_ui.fill_between(FillBetween::new("95% confidence", upper, lower));
+/// });
+/// # });
+/// ```
+pub struct FillBetween<'a> {
+    base: PlotItemBase,
+    pub(super) first: PlotPoints<'a>,
+    pub(super) second: PlotPoints<'a>,
+    pub(super) color: Color32,
+    below_color: Option<Color32>,
+    fill_alpha: f32,
+    stroke: Stroke,
+}
+
+impl<'a> FillBetween<'a> {
+    pub fn new(
+        name: impl Into<String>,
+        first: impl Into<PlotPoints<'a>>,
+        second: impl Into<PlotPoints<'a>>,
+    ) -> Self {
+        Self {
+            base: PlotItemBase::new(name.into()),
+            first: first.into(),
+            second: second.into(),
+            color: Color32::TRANSPARENT,
+            below_color: None,
+            fill_alpha: 0.25,
+            stroke: Stroke::NONE,
+        }
+    }
+
+    /// Color of the area where the first series is above the second one.
+    /// Default is `Color32::TRANSPARENT` which means a color will be auto-assigned.
+    #[inline]
+    pub fn color(mut self, color: impl Into<Color32>) -> Self {
+        self.color = color.into();
+        self
+    }
+
+    /// Color of the area where the first series is below the second one.
+    /// Default: the same as [`Self::color`].
+    #[inline]
+    pub fn below_color(mut self, color: impl Into<Color32>) -> Self {
+        self.below_color = Some(color.into());
+        self
+    }
+
+    /// Set the fill's alpha channel. Default is `0.25`.
+    #[inline]
+    pub fn fill_alpha(mut self, alpha: impl Into<f32>) -> Self {
+        self.fill_alpha = alpha.into();
+        self
+    }
+
+    /// Draw both series as lines with this stroke. Default: no lines.
+    #[inline]
+    pub fn stroke(mut self, stroke: impl Into<Stroke>) -> Self {
+        self.stroke = stroke.into();
+        self
+    }
+
+    builder_methods_for_base!();
+}
+
+impl PlotItem for FillBetween<'_> {
+    fn shapes(&self, _ui: &Ui, transform: &PlotTransform, shapes: &mut Vec<Shape>) {
+        let mut fill_alpha = self.fill_alpha;
+        if self.base.highlight {
+            fill_alpha = (2.0 * fill_alpha).at_most(1.0);
+        }
+        let fill = |color: Color32| -> Color32 {
+            Rgba::from(color).to_opaque().multiply(fill_alpha).into()
+        };
+        let above_color = fill(self.color);
+        let below_color = fill(self.below_color.unwrap_or(self.color));
+
+        let mut mesh = Mesh::default();
+        for (quad, first_is_above) in fill_between_quads(self.first.points(), self.second.points())
+        {
+            let color = if first_is_above {
+                above_color
+            } else {
+                below_color
+            };
+            let index = mesh.vertices.len() as u32;
+            for point in &quad {
+                mesh.colored_vertex(transform.position_from_point(point), color);
+            }
+            mesh.add_triangle(index, index + 1, index + 2);
+            mesh.add_triangle(index, index + 2, index + 3);
+        }
+        shapes.push(Shape::mesh(mesh));
+
+        if 0.0 < self.stroke.width {
+            let stroke = Stroke::new(self.stroke.width, self.stroke.color);
+            for series in [&self.first, &self.second] {
+                let points = series
+                    .points()
+                    .iter()
+                    .map(|point| transform.position_from_point(point))
+                    .collect();
+                LineStyle::Solid.style_line(points, stroke, self.base.highlight, shapes);
+            }
+        }
+    }
+
+    fn initialize(&mut self, x_range: RangeInclusive<f64>) {
+        self.first.generate_points(x_range.clone());
+        self.second.generate_points(x_range);
+    }
+
+    fn color(&self) -> Color32 {
+        self.color
+    }
+
+    fn geometry(&self) -> PlotGeometry<'_> {
+        // Neither series alone stands for the area, so hovering has its own `find_closest`:
+        PlotGeometry::None
+    }
+
+    fn bounds(&self) -> PlotBounds {
+        let mut bounds = self.first.bounds();
+        bounds.merge(&self.second.bounds());
+        bounds
     }
+
+    fn find_closest(&self, point: Pos2, transform: &PlotTransform) -> Option<ClosestElem> {
+        self.first
+            .points()
+            .iter()
+            .chain(self.second.points())
+            .enumerate()
+            .map(|(index, value)| ClosestElem {
+                index,
+                dist_sq: point.distance_sq(transform.position_from_point(value)),
+            })
+            .min_by_key(|elem| elem.dist_sq.ord())
+    }
+
+    fn on_hover(
+        &self,
+        plot_area_response: &egui::Response,
+        elem: ClosestElem,
+        shapes: &mut Vec<Shape>,
+        cursors: &mut Vec<Cursor>,
+        plot: &PlotConfig<'_>,
+        label_formatter: &LabelFormatter<'_>,
+    ) {
+        let first = self.first.points();
+        let value = first
+            .get(elem.index)
+            .or_else(|| self.second.points().get(elem.index - first.len()))
+            .copied();
+        let Some(value) = value else {
+            return;
+        };
+        let pointer = plot.transform.position_from_point(&value);
+        shapes.push(Shape::circle_filled(pointer, 3.0, rulers_color(plot.ui)));
+        rulers_and_tooltip_at_value(
+            plot_area_response,
+            value,
+            [None, None],
+            self.name(),
+            plot,
+            cursors,
+            label_formatter,
+        );
+    }
+
+    fn base(&self) -> &PlotItemBase {
+        &self.base
+    }
+
+    fn base_mut(&mut self) -> &mut PlotItemBase {
+        &mut self.base
+    }
+}
+
+/// The area between two series sorted by x, as quads with two vertical sides, and whether
+/// the first series is above the second one in each.
+///
+/// Both series are sampled at all x values of either, and split where they cross.
+fn fill_between_quads(first: &[PlotPoint], second: &[PlotPoint]) -> Vec<([PlotPoint; 4], bool)> {
+    let (Some(first_start), Some(first_end), Some(second_start), Some(second_end)) =
+        (first.first(), first.last(), second.first(), second.last())
+    else {
+        return Vec::new();
+    };
+    let start = first_start.x.max(second_start.x);
+    let end = first_end.x.min(second_end.x);
+
+    let mut xs: Vec<f64> = first
+        .iter()
+        .chain(second)
+        .map(|point| point.x)
+        .filter(|x| (start..=end).contains(x))
+        .collect();
+    xs.sort_by(|a, b| a.total_cmp(b));
+    xs.dedup();
+
+    let mut quads = Vec::new();
+    let mut add_quad = |x0: f64, x1: f64, [a0, a1, b0, b1]: [f64; 4]| {
+        let quad = [
+            PlotPoint::new(x0, a0),
+            PlotPoint::new(x1, a1),
+            PlotPoint::new(x1, b1),
+            PlotPoint::new(x0, b0),
+        ];
+        quads.push((quad, a0 + a1 >= b0 + b1));
+    };
+    for pair in xs.windows(2) {
+        let (x0, x1) = (pair[0], pair[1]);
+        let (Some(a0), Some(a1), Some(b0), Some(b1)) = (
+            y_at(first, x0),
+            y_at(first, x1),
+            y_at(second, x0),
+            y_at(second, x1),
+        ) else {
+            continue;
+        };
+        let (d0, d1) = (a0 - b0, a1 - b1);
+        if d0 * d1 < 0.0 {
+            // The series cross in between:
+            let t = d0 / (d0 - d1);
+            let x = x0 + t * (x1 - x0);
+            let y = a0 + t * (a1 - a0);
+            add_quad(x0, x, [a0, y, b0, y]);
+            add_quad(x, x1, [y, a1, y, b1]);
+        } else {
+            add_quad(x0, x1, [a0, a1, b0, b1]);
+        }
+    }
+    quads
+}
+
+/// The y value of a series sorted by x at `x`, interpolating linearly between its points.
+fn y_at(series: &[PlotPoint], x: f64) -> Option<f64> {
+    let index = series.partition_point(|point| point.x < x);
+    let after = series.get(index)?;
+    if after.x == x {
+        return after.y.is_finite().then_some(after.y);
+    }
+    let before = series.get(index.checked_sub(1)?)?;
+    let t = (x - before.x) / (after.x - before.x);
+    let y = before.y + t * (after.y - before.y);
+    y.is_finite().then_some(y)
+}
+
+#[test]
+fn test_fill_between_quads() {
+    let points = |points: &[[f64; 2]]| -> Vec<PlotPoint> {
+        points.iter().map(|&point| point.into()).collect()
+    };
+    // The first series goes from below to above the second, which has a sample in between:
+    let first = points(&[[0.0, 0.0], [2.0, 2.0], [3.0, 2.0]]);
+    let second = points(&[[-1.0, 1.0], [1.5, 1.0], [2.0, 1.0]]);
+
+    let quads = fill_between_quads(&first, &second);
+    let spans: Vec<(f64, f64, bool)> = quads
+        .iter()
+        .map(|(quad, above)| (quad[0].x, quad[1].x, *above))
+        .collect();
+    assert_eq!(
+        spans,
+        [(0.0, 1.0, false), (1.0, 1.5, true), (1.5, 2.0, true)]
+    );
+    // Where they cross, the quad narrows to a point:
+    assert_eq!(quads[0].0[1], quads[0].0[2]);
+    assert_eq!(y_at(&first, 1.0), Some(1.0));
+    assert_eq!(y_at(&first, 3.5), None);
 }
 
 /// A convex polygon.
@@ -759,6 +1184,14 @@ pub struct Points<'a> {
     pub(super) radius: f32,
 
     pub(super) stems: Option<f32>,
+
+    errors: PointErrors,
+
+    sorted_by_x: bool,
+
+    /// Per-point values to color the markers by, see [`Self::color_by_value`].
+    color_values: Option<(Vec<f64>, Colormap)>,
+    color_range: Option<RangeInclusive<f64>>,
 }
 
 impl<'a> Points<'a> {
@@ -771,6 +1204,10 @@ impl<'a> Points<'a> {
             filled: true,
             radius: 1.0,
             stems: None,
+            errors: PointErrors::default(),
+            sorted_by_x: false,
+            color_values: None,
+            color_range: None,
         }
     }
 
@@ -788,6 +1225,25 @@ impl<'a> Points<'a> {
         self
     }
 
+    /// Color each marker by a value, e.g. a third dimension of the data, using `colormap`.
+    ///
+    /// The `values` go with the points in order. The colors span the range of the values,
+    /// unless it is set with [`Self::color_range`]. Show which color is which value with
+    /// [`Colorbar::for_item`]. The stems, error bars and legend keep the [color](Self::color)
+    /// of the item.
+    #[inline]
+    pub fn color_by_value(mut self, values: impl Into<Vec<f64>>, colormap: Colormap) -> Self {
+        self.color_values = Some((values.into(), colormap));
+        self
+    }
+
+    /// The values that get the first and last color of [`Self::color_by_value`].
+    #[inline]
+    pub fn color_range(mut self, range: RangeInclusive<f64>) -> Self {
+        self.color_range = Some(range);
+        self
+    }
+
     /// Whether to fill the marker.
     #[inline]
     pub fn filled(mut self, filled: bool) -> Self {
@@ -809,6 +1265,20 @@ impl<'a> Points<'a> {
         self
     }
 
+    /// Promise that the points are sorted by x, see [`Line::sorted_by_x`].
+    #[inline]
+    pub fn sorted_by_x(mut self, sorted: bool) -> Self {
+        self.sorted_by_x = sorted;
+        self
+    }
+
+    /// Draw error bars at the points, in the color of the markers.
+    #[inline]
+    pub fn error_bars(mut self, errors: PointErrors) -> Self {
+        self.errors = errors;
+        self
+    }
+
     builder_methods_for_base!();
 }
 
@@ -849,12 +1319,39 @@ impl PlotItem for Points<'_> {
 
         let y_reference = stems.map(|y| transform.position_from_point(&PlotPoint::new(0.0, y)).y);
 
+        self.errors.shapes(
+            series.points(),
+            transform,
+            Stroke::new(stem_stroke.width.at_least(1.0), *color),
+            DEFAULT_ERROR_CAP_WIDTH,
+            shapes,
+        );
+
+        let color_mapping = self.color_mapping(transform.bounds());
+        let marker_color = |index: usize| {
+            let (colormap, range) = color_mapping.as_ref()?;
+            let (values, _) = self.color_values.as_ref()?;
+            Some(colormap.color_for_value(*values.get(index)?, range))
+        };
+
         series
             .points()
             .iter()
-            .map(|value| transform.position_from_point(value))
-            .for_each(|center| {
+            .enumerate()
+            .map(|(index, value)| (index, transform.position_from_point(value)))
+            .for_each(|(index, center)| {
                 let tf = |dx: f32, dy: f32| -> Pos2 { center + radius * vec2(dx, dy) };
+                let (fill, stroke, default_stroke) = match marker_color(index) {
+                    Some(color) => {
+                        let marker_stroke = Stroke::new(stroke_size, color);
+                        if *filled {
+                            (color, Stroke::NONE, marker_stroke)
+                        } else {
+                            (Color32::TRANSPARENT, marker_stroke, marker_stroke)
+                        }
+                    }
+                    None => (fill, stroke, default_stroke),
+                };
 
                 if let Some(y) = y_reference {
                     let stem = Shape::line_segment([center, pos2(center.x, y)], stem_stroke);
@@ -945,6 +1442,7 @@ impl PlotItem for Points<'_> {
     }
 
     fn initialize(&mut self, x_range: RangeInclusive<f64>) {
+        self.sorted_by_x |= matches!(self.series, PlotPoints::Generator(_));
         self.series.generate_points(x_range);
     }
 
@@ -952,12 +1450,33 @@ impl PlotItem for Points<'_> {
         self.color
     }
 
+    fn color_mapping(&self, _visible: &PlotBounds) -> Option<(Colormap, RangeInclusive<f64>)> {
+        let (values, colormap) = self.color_values.as_ref()?;
+        let range = self
+            .color_range
+            .clone()
+            .unwrap_or_else(|| finite_range(values));
+        Some((colormap.clone(), range))
+    }
+
     fn geometry(&self) -> PlotGeometry<'_> {
         PlotGeometry::Points(self.series.points())
     }
 
     fn bounds(&self) -> PlotBounds {
-        self.series.bounds()
+        if self.errors.is_empty() {
+            self.series.bounds()
+        } else {
+            self.errors.bounds(self.series.points())
+        }
+    }
+
+    fn point_errors(&self, index: usize) -> [Option<[f64; 2]>; 2] {
+        self.errors.at(index)
+    }
+
+    fn sorted_by_x(&self) -> bool {
+        self.sorted_by_x
     }
 
     fn base(&self) -> &PlotItemBase {
@@ -969,54 +1488,179 @@ impl PlotItem for Points<'_> {
     }
 }
 
-/// A set of arrows.
-pub struct Arrows<'a> {
+#[test]
+fn test_points_color_by_value() {
+    let points = Points::new("depth", vec![[0.0, 0.0], [1.0, 1.0], [2.0, 2.0]])
+        .color_by_value(vec![2.0, f64::NAN, 5.0], Colormap::viridis());
+    let (_, range) = points.color_mapping(&PlotBounds::NOTHING).unwrap();
+    assert_eq!(range, 2.0..=5.0);
+
+    let points = points.color_range(0.0..=10.0);
+    assert_eq!(
+        points.color_mapping(&PlotBounds::NOTHING).unwrap().1,
+        0.0..=10.0
+    );
+    assert!(
+        Points::new("plain", vec![[0.0, 0.0]])
+            .color_mapping(&PlotBounds::NOTHING)
+            .is_none()
+    );
+}
+
+/// Error bars with caps, e.g. for measurements with an uncertainty.
+///
+/// The errors can be symmetric or asymmetric, along x and/or y, see [`PointErrors`].
+/// To draw error bars together with markers or a line, see [`Points::error_bars`]
+/// and [`Line::error_bars`].
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// use egui_plot::{ErrorBars, Plot, PointErrors};
+///
+/// let errors = PointErrors::new().y_asymmetric([0.1, 0.2, 0.1], [0.3, 0.2, 0.4]);
+/// let bars = ErrorBars::new("measured", vec![[1.0, 2.0], [2.0, 2.5], [3.0, 2.2]], errors);
+/// Plot::new("measurements").show(ui, |plot_ui| plot_ui.error_bars(bars));
+/// # });
+/// ```
+pub struct ErrorBars<'a> {
     base: PlotItemBase,
-    pub(super) origins: PlotPoints<'a>,
-    pub(super) tips: PlotPoints<'a>,
-    pub(super) tip_length: Option<f32>,
-    pub(super) color: Color32,
+    pub(super) series: PlotPoints<'a>,
+    errors: PointErrors,
+    pub(super) stroke: Stroke,
+    cap_width: f32,
 }
 
-impl<'a> Arrows<'a> {
+impl<'a> ErrorBars<'a> {
     pub fn new(
         name: impl Into<String>,
-        origins: impl Into<PlotPoints<'a>>,
-        tips: impl Into<PlotPoints<'a>>,
+        series: impl Into<PlotPoints<'a>>,
+        errors: PointErrors,
     ) -> Self {
         Self {
             base: PlotItemBase::new(name.into()),
-            origins: origins.into(),
-            tips: tips.into(),
-            tip_length: None,
-            color: Color32::TRANSPARENT,
+            series: series.into(),
+            errors,
+            stroke: Stroke::new(1.0, Color32::TRANSPARENT),
+            cap_width: DEFAULT_ERROR_CAP_WIDTH,
         }
     }
 
-    /// Set the length of the arrow tips
+    /// Stroke of the bars and caps.
     #[inline]
-    pub fn tip_length(mut self, tip_length: f32) -> Self {
-        self.tip_length = Some(tip_length);
+    pub fn stroke(mut self, stroke: impl Into<Stroke>) -> Self {
+        self.stroke = stroke.into();
         self
     }
 
-    /// Set the arrows' color.
+    /// Stroke color. Default is `Color32::TRANSPARENT` which means a color will be auto-assigned.
     #[inline]
     pub fn color(mut self, color: impl Into<Color32>) -> Self {
-        self.color = color.into();
+        self.stroke.color = color.into();
+        self
+    }
+
+    /// Width of the caps at the ends of the bars, in ui points. Default: `6.0`.
+    #[inline]
+    pub fn cap_width(mut self, cap_width: f32) -> Self {
+        self.cap_width = cap_width;
         self
     }
 
     builder_methods_for_base!();
 }
 
-impl PlotItem for Arrows<'_> {
+impl PlotItem for ErrorBars<'_> {
     fn shapes(&self, _ui: &Ui, transform: &PlotTransform, shapes: &mut Vec<Shape>) {
-        let Self {
-            origins,
-            tips,
-            tip_length,
-            color,
+        let mut stroke = self.stroke;
+        if self.base.highlight {
+            stroke.width *= 2.0;
+        }
+        self.errors.shapes(
+            self.series.points(),
+            transform,
+            stroke,
+            self.cap_width,
+            shapes,
+        );
+    }
+
+    fn initialize(&mut self, x_range: RangeInclusive<f64>) {
+        self.series.generate_points(x_range);
+    }
+
+    fn color(&self) -> Color32 {
+        self.stroke.color
+    }
+
+    fn geometry(&self) -> PlotGeometry<'_> {
+        PlotGeometry::Points(self.series.points())
+    }
+
+    fn bounds(&self) -> PlotBounds {
+        self.errors.bounds(self.series.points())
+    }
+
+    fn point_errors(&self, index: usize) -> [Option<[f64; 2]>; 2] {
+        self.errors.at(index)
+    }
+
+    fn base(&self) -> &PlotItemBase {
+        &self.base
+    }
+
+    fn base_mut(&mut self) -> &mut PlotItemBase {
+        &mut self.base
+    }
+}
+
+/// A set of arrows.
+pub struct Arrows<'a> {
+    base: PlotItemBase,
+    pub(super) origins: PlotPoints<'a>,
+    pub(super) tips: PlotPoints<'a>,
+    pub(super) tip_length: Option<f32>,
+    pub(super) color: Color32,
+}
+
+impl<'a> Arrows<'a> {
+    pub fn new(
+        name: impl Into<String>,
+        origins: impl Into<PlotPoints<'a>>,
+        tips: impl Into<PlotPoints<'a>>,
+    ) -> Self {
+        Self {
+            base: PlotItemBase::new(name.into()),
+            origins: origins.into(),
+            tips: tips.into(),
+            tip_length: None,
+            color: Color32::TRANSPARENT,
+        }
+    }
+
+    /// Set the length of the arrow tips
+    #[inline]
+    pub fn tip_length(mut self, tip_length: f32) -> Self {
+        self.tip_length = Some(tip_length);
+        self
+    }
+
+    /// Set the arrows' color.
+    #[inline]
+    pub fn color(mut self, color: impl Into<Color32>) -> Self {
+        self.color = color.into();
+        self
+    }
+
+    builder_methods_for_base!();
+}
+
+impl PlotItem for Arrows<'_> {
+    fn shapes(&self, _ui: &Ui, transform: &PlotTransform, shapes: &mut Vec<Shape>) {
+        let Self {
+            origins,
+            tips,
+            tip_length,
+            color,
             base,
             ..
         } = self;
@@ -1170,7 +1814,14 @@ impl PlotItem for PlotImage {
             let right_bottom_screen = transform.position_from_point(&right_bottom);
             Rect::from_two_pos(left_top_screen, right_bottom_screen)
         };
-        let screen_rotation = -*rotation as f32;
+        // The image is always drawn upright, but `rotation` is in plot coordinates,
+        // so its direction on screen flips with each inverted axis.
+        let inverted = transform.inverted();
+        let screen_rotation = if inverted.x == inverted.y {
+            -*rotation as f32
+        } else {
+            *rotation as f32
+        };
 
         egui::paint_texture_at(
             ui.painter(),
@@ -1239,6 +1890,715 @@ impl PlotItem for PlotImage {
 
 // ----------------------------------------------------------------------------
 
+/// A grid of values shown as colored cells, e.g. a correlation matrix or an occupancy grid.
+///
+/// The values are given row by row. By default the first row is at the top, and the cells
+/// span one plot unit each, starting at the origin; see [`Self::extent`] to place them elsewhere.
+/// Hovering a cell shows its row, column and value.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// use egui_plot::{Colorbar, Colormap, Heatmap, Plot};
+///
+/// let correlations = vec![1.0, 0.3, -0.5, 0.3, 1.0, 0.1, -0.5, 0.1, 1.0];
+/// let heatmap = Heatmap::new("correlation", correlations, 3, 3)
+///     .colormap(Colormap::cool_warm())
+///     .value_range(-1.0..=1.0)
+///     .show_values(true);
+/// Plot::new("correlation")
+///     .colorbar(Colorbar::for_item("correlation"))
+///     .show(ui, |plot_ui| plot_ui.heatmap(heatmap));
+/// # });
+/// ```
+pub struct Heatmap {
+    base: PlotItemBase,
+    pub(crate) values: Vec<f64>,
+    num_rows: usize,
+    num_columns: usize,
+    extent: PlotBounds,
+    first_row_at_bottom: bool,
+    colormap: Colormap,
+    value_range: Option<RangeInclusive<f64>>,
+    show_values: bool,
+    value_formatter: Option<Box<dyn Fn(f64) -> String>>,
+}
+
+impl Heatmap {
+    /// A heatmap of `num_rows × num_columns` values, given row by row.
+    ///
+    /// Missing values are left empty, as are values that are `NaN`.
+    pub fn new(
+        name: impl Into<String>,
+        values: impl Into<Vec<f64>>,
+        num_rows: usize,
+        num_columns: usize,
+    ) -> Self {
+        let mut values = values.into();
+        values.resize(num_rows * num_columns, f64::NAN);
+        Self {
+            base: PlotItemBase::new(name.into()),
+            values,
+            num_rows,
+            num_columns,
+            extent: PlotBounds::from_min_max([0.0, 0.0], [num_columns as f64, num_rows as f64]),
+            first_row_at_bottom: false,
+            colormap: Colormap::default(),
+            value_range: None,
+            show_values: false,
+            value_formatter: None,
+        }
+    }
+
+    /// The area covered by all cells, in plot coordinates.
+    ///
+    /// Default: one plot unit per cell, from `(0, 0)` to `(num_columns, num_rows)`.
+    #[inline]
+    pub fn extent(mut self, extent: PlotBounds) -> Self {
+        self.extent = extent;
+        self
+    }
+
+    /// Put the first row at the bottom instead of at the top, e.g. for spectrograms
+    /// where the first row is the lowest frequency. Default: `false`.
+    #[inline]
+    pub fn first_row_at_bottom(mut self, first_row_at_bottom: bool) -> Self {
+        self.first_row_at_bottom = first_row_at_bottom;
+        self
+    }
+
+    /// The colors of the cells. Default: [`Colormap::viridis`].
+    #[inline]
+    pub fn colormap(mut self, colormap: Colormap) -> Self {
+        self.colormap = colormap;
+        self
+    }
+
+    /// The values at the start and end of the colormap.
+    ///
+    /// Default: from the smallest to the largest value.
+    #[inline]
+    pub fn value_range(mut self, range: RangeInclusive<f64>) -> Self {
+        self.value_range = Some(range);
+        self
+    }
+
+    /// Write the value in each cell that is large enough for it. Default: `false`.
+    #[inline]
+    pub fn show_values(mut self, show_values: bool) -> Self {
+        self.show_values = show_values;
+        self
+    }
+
+    /// Format the values in the cells and in the hover label.
+    pub fn value_formatter(mut self, formatter: impl Fn(f64) -> String + 'static) -> Self {
+        self.value_formatter = Some(Box::new(formatter));
+        self
+    }
+
+    /// A [`Colorbar`] with the current colormap and value range of this heatmap,
+    /// e.g. to show on its own. [`Colorbar::for_item`] follows changes to the heatmap instead.
+    pub fn colorbar(&self) -> Colorbar<'static> {
+        Colorbar::new(self.colormap.clone(), self.color_range())
+    }
+
+    /// The value range the colormap spans.
+    fn color_range(&self) -> RangeInclusive<f64> {
+        self.value_range
+            .clone()
+            .unwrap_or_else(|| finite_range(&self.values))
+    }
+
+    fn format_value(&self, value: f64) -> String {
+        match &self.value_formatter {
+            Some(formatter) => formatter(value),
+            None => crate::format_number(value, 2),
+        }
+    }
+
+    /// The corners of the cell at `row` and `column`, in plot coordinates.
+    fn cell_bounds(&self, row: usize, column: usize) -> (PlotPoint, PlotPoint) {
+        let width = self.extent.width() / self.num_columns as f64;
+        let height = self.extent.height() / self.num_rows as f64;
+        let rows_from_bottom = if self.first_row_at_bottom {
+            row
+        } else {
+            self.num_rows - 1 - row
+        };
+        let min = PlotPoint::new(
+            self.extent.min()[0] + column as f64 * width,
+            self.extent.min()[1] + rows_from_bottom as f64 * height,
+        );
+        let max = PlotPoint::new(min.x + width, min.y + height);
+        (min, max)
+    }
+
+    /// The row and column of the cell containing `value`, if any.
+    fn cell_at(&self, value: PlotPoint) -> Option<(usize, usize)> {
+        let [min_x, min_y] = self.extent.min();
+        let column = (value.x - min_x) / self.extent.width() * self.num_columns as f64;
+        let rows_from_bottom = (value.y - min_y) / self.extent.height() * self.num_rows as f64;
+        let in_grid = |index: f64, len: usize| (0.0..len as f64).contains(&index);
+        if !in_grid(column, self.num_columns) || !in_grid(rows_from_bottom, self.num_rows) {
+            return None;
+        }
+        let rows_from_bottom = rows_from_bottom as usize;
+        let row = if self.first_row_at_bottom {
+            rows_from_bottom
+        } else {
+            self.num_rows - 1 - rows_from_bottom
+        };
+        Some((row, column as usize))
+    }
+
+    builder_methods_for_base!();
+}
+
+impl PlotItem for Heatmap {
+    fn shapes(&self, ui: &Ui, transform: &PlotTransform, shapes: &mut Vec<Shape>) {
+        if self.num_rows == 0 || self.num_columns == 0 {
+            return;
+        }
+        let range = self.color_range();
+        let font_id = TextStyle::Small.resolve(ui.style());
+        let row_height = ui.fonts(|f| f.row_height(&font_id));
+        let mut mesh = Mesh::default();
+        let mut texts = Vec::new();
+        for (index, &value) in self.values.iter().enumerate() {
+            let (row, column) = (index / self.num_columns, index % self.num_columns);
+            let (min, max) = self.cell_bounds(row, column);
+            let rect = transform.rect_from_values(&min, &max);
+            if !transform.frame().intersects(rect) {
+                continue;
+            }
+            let color = self.colormap.color_for_value(value, &range);
+            if color == Color32::TRANSPARENT {
+                continue;
+            }
+            mesh.add_colored_rect(rect, color);
+
+            if self.show_values && row_height <= rect.height() {
+                let text = self.format_value(value);
+                // Skip laying out text that clearly doesn't fit, even with narrow characters:
+                let min_width = 0.25 * font_id.size * text.chars().count() as f32;
+                if rect.width() < min_width {
+                    continue;
+                }
+                let text_color = if color.intensity() > 0.5 {
+                    Color32::BLACK
+                } else {
+                    Color32::WHITE
+                };
+                let galley = ui.fonts(|f| f.layout_no_wrap(text, font_id.clone(), text_color));
+                if galley.size().x <= rect.width() && galley.size().y <= rect.height() {
+                    let pos = rect.center() - 0.5 * galley.size();
+                    texts.push(Shape::galley(pos, galley, text_color));
+                }
+            }
+        }
+        shapes.push(Shape::mesh(mesh));
+        shapes.extend(texts);
+
+        if self.base.highlight {
+            let rect = transform.rect_from_values(
+                &PlotPoint::from(self.extent.min()),
+                &PlotPoint::from(self.extent.max()),
+            );
+            shapes.push(Shape::rect_stroke(
+                rect,
+                0.0,
+                Stroke::new(2.0, ui.visuals().strong_text_color()),
+                egui::StrokeKind::Outside,
+            ));
+        }
+    }
+
+    fn initialize(&mut self, _x_range: RangeInclusive<f64>) {}
+
+    fn color(&self) -> Color32 {
+        self.colormap.color_at(0.5)
+    }
+
+    fn color_mapping(&self, _visible: &PlotBounds) -> Option<(Colormap, RangeInclusive<f64>)> {
+        Some((self.colormap.clone(), self.color_range()))
+    }
+
+    fn geometry(&self) -> PlotGeometry<'_> {
+        PlotGeometry::Rects
+    }
+
+    fn bounds(&self) -> PlotBounds {
+        self.extent
+    }
+
+    fn find_closest(&self, point: Pos2, transform: &PlotTransform) -> Option<ClosestElem> {
+        let (row, column) = self.cell_at(transform.value_from_position(point))?;
+        Some(ClosestElem {
+            index: row * self.num_columns + column,
+            dist_sq: 0.0,
+        })
+    }
+
+    fn on_hover(
+        &self,
+        plot_area_response: &egui::Response,
+        elem: ClosestElem,
+        shapes: &mut Vec<Shape>,
+        _cursors: &mut Vec<Cursor>,
+        plot: &PlotConfig<'_>,
+        _: &LabelFormatter<'_>,
+    ) {
+        let (row, column) = (elem.index / self.num_columns, elem.index % self.num_columns);
+        let (min, max) = self.cell_bounds(row, column);
+        shapes.push(Shape::rect_stroke(
+            plot.transform.rect_from_values(&min, &max),
+            0.0,
+            Stroke::new(2.0, plot.ui.visuals().strong_text_color()),
+            egui::StrokeKind::Inside,
+        ));
+
+        let prefix = if self.name().is_empty() {
+            String::new()
+        } else {
+            format!("{}\n", self.name())
+        };
+        let value = self.values[elem.index];
+        show_tooltip(
+            plot_area_response,
+            format!(
+                "{prefix}row {row}, column {column}\n{}",
+                self.format_value(value)
+            ),
+        );
+    }
+
+    fn base(&self) -> &PlotItemBase {
+        &self.base
+    }
+
+    fn base_mut(&mut self) -> &mut PlotItemBase {
+        &mut self.base
+    }
+}
+
+#[test]
+fn test_heatmap_cells() {
+    let heatmap = Heatmap::new("", vec![0.0; 6], 2, 3)
+        .extent(PlotBounds::from_min_max([0.0, 0.0], [6.0, 1.0]));
+    assert_eq!(heatmap.cell_at(PlotPoint::new(0.5, 0.9)), Some((0, 0)));
+    assert_eq!(heatmap.cell_at(PlotPoint::new(5.5, 0.1)), Some((1, 2)));
+    assert_eq!(heatmap.cell_at(PlotPoint::new(6.5, 0.1)), None);
+    let (min, max) = heatmap.cell_bounds(1, 2);
+    assert_eq!((min.x, min.y, max.x, max.y), (4.0, 0.0, 6.0, 0.5));
+
+    let spectrogram = Heatmap::new("", vec![0.0; 6], 2, 3).first_row_at_bottom(true);
+    assert_eq!(spectrogram.cell_at(PlotPoint::new(0.5, 0.5)), Some((0, 0)));
+    assert_eq!(spectrogram.color_range(), 0.0..=0.0);
+}
+
+// ----------------------------------------------------------------------------
+
+/// Where the values of a [`Contour`] come from.
+enum ContourField<'a> {
+    Cells {
+        values: Vec<f64>,
+        num_rows: usize,
+        num_columns: usize,
+        first_row_at_bottom: bool,
+    },
+    Function {
+        function: Box<dyn Fn(f64, f64) -> f64 + 'a>,
+        resolution: [usize; 2],
+    },
+}
+
+/// Contour lines of a scalar field, and optionally filled bands between them.
+///
+/// The field is either a grid of values, laid out like a [`Heatmap`] with the same extent,
+/// or a function that is sampled over the visible bounds each frame.
+/// The lines are computed with marching squares.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// use egui_plot::{Colormap, Contour, Plot};
+///
+/// let hill = Contour::from_fn("height", |x, y| (-(x * x + y * y)).exp())
+///     .levels([0.2, 0.4, 0.6, 0.8])
+///     .colormap(Colormap::viridis())
+///     .show_labels(true);
+/// Plot::new("hill")
+///     .data_aspect(1.0)
+///     .show(ui, |plot_ui| plot_ui.contour(hill));
+/// # });
+/// ```
+pub struct Contour<'a> {
+    base: PlotItemBase,
+    field: ContourField<'a>,
+    extent: PlotBounds,
+    levels: Option<Vec<f64>>,
+    num_levels: usize,
+    pub(crate) stroke: Stroke,
+    colormap: Option<Colormap>,
+    value_range: Option<RangeInclusive<f64>>,
+    filled: bool,
+    show_labels: bool,
+}
+
+impl<'a> Contour<'a> {
+    /// Contours of a grid of `num_rows × num_columns` values, given row by row.
+    ///
+    /// Each value is taken to be at the center of its cell, and by default the cells span
+    /// one plot unit each, with the first row at the top; just like a [`Heatmap`].
+    /// `NaN` values leave a hole.
+    pub fn new(
+        name: impl Into<String>,
+        values: impl Into<Vec<f64>>,
+        num_rows: usize,
+        num_columns: usize,
+    ) -> Self {
+        Self::with_field(
+            name,
+            ContourField::Cells {
+                values: values.into(),
+                num_rows,
+                num_columns,
+                first_row_at_bottom: false,
+            },
+            PlotBounds::from_min_max([0.0, 0.0], [num_columns as f64, num_rows as f64]),
+        )
+    }
+
+    /// Contours of `function(x, y)`, sampled over the visible part of the plot.
+    ///
+    /// The function doesn't count towards the automatic bounds of the plot.
+    pub fn from_fn(name: impl Into<String>, function: impl Fn(f64, f64) -> f64 + 'a) -> Self {
+        Self::with_field(
+            name,
+            ContourField::Function {
+                function: Box::new(function),
+                resolution: [100, 100],
+            },
+            PlotBounds::NOTHING,
+        )
+    }
+
+    fn with_field(name: impl Into<String>, field: ContourField<'a>, extent: PlotBounds) -> Self {
+        Self {
+            base: PlotItemBase::new(name.into()),
+            field,
+            extent,
+            levels: None,
+            num_levels: 10,
+            stroke: Stroke::new(1.0, Color32::TRANSPARENT),
+            colormap: None,
+            value_range: None,
+            filled: false,
+            show_labels: false,
+        }
+    }
+
+    /// The area covered by the cells of the grid given to [`Self::new`], in plot coordinates.
+    ///
+    /// Default: one plot unit per cell, from `(0, 0)` to `(num_columns, num_rows)`.
+    /// Has no effect on contours of a function.
+    #[inline]
+    pub fn extent(mut self, extent: PlotBounds) -> Self {
+        if let ContourField::Cells { .. } = self.field {
+            self.extent = extent;
+        }
+        self
+    }
+
+    /// Put the first row of the grid given to [`Self::new`] at the bottom instead of at the top.
+    /// Default: `false`.
+    #[inline]
+    pub fn first_row_at_bottom(mut self, at_bottom: bool) -> Self {
+        if let ContourField::Cells {
+            first_row_at_bottom,
+            ..
+        } = &mut self.field
+        {
+            *first_row_at_bottom = at_bottom;
+        }
+        self
+    }
+
+    /// How many points the function of [`Self::from_fn`] is sampled at, along x and y.
+    /// Default: `100 × 100`.
+    #[inline]
+    pub fn resolution(mut self, num_x: usize, num_y: usize) -> Self {
+        if let ContourField::Function { resolution, .. } = &mut self.field {
+            *resolution = [num_x, num_y];
+        }
+        self
+    }
+
+    /// Draw contours at these values.
+    ///
+    /// By default there are about 10 levels at "nice" values, see [`Self::num_levels`].
+    #[inline]
+    pub fn levels(mut self, levels: impl IntoIterator<Item = f64>) -> Self {
+        self.levels = Some(levels.into_iter().collect());
+        self
+    }
+
+    /// About how many levels to draw when they are picked automatically. Default: `10`.
+    #[inline]
+    pub fn num_levels(mut self, num_levels: usize) -> Self {
+        self.num_levels = num_levels;
+        self
+    }
+
+    /// Stroke of the contour lines.
+    ///
+    /// The color is only used without a colormap, or when the bands are [`Self::filled`].
+    #[inline]
+    pub fn stroke(mut self, stroke: impl Into<Stroke>) -> Self {
+        self.stroke = stroke.into();
+        self
+    }
+
+    /// Color each contour line, and each filled band, by its value.
+    #[inline]
+    pub fn colormap(mut self, colormap: Colormap) -> Self {
+        self.colormap = Some(colormap);
+        self
+    }
+
+    /// The values at the start and end of the colormap.
+    ///
+    /// Default: from the smallest to the largest value. Use the same range as a [`Heatmap`]
+    /// to give both the same colors.
+    #[inline]
+    pub fn value_range(mut self, range: RangeInclusive<f64>) -> Self {
+        self.value_range = Some(range);
+        self
+    }
+
+    /// Fill the bands between the levels with the colors of the colormap
+    /// ([`Colormap::viridis`] if none is set). Default: `false`.
+    ///
+    /// The lines are then drawn with the color of the [`Self::stroke`].
+    #[inline]
+    pub fn filled(mut self, filled: bool) -> Self {
+        self.filled = filled;
+        self
+    }
+
+    /// Write the level on each contour line that is long enough for it. Default: `false`.
+    #[inline]
+    pub fn show_labels(mut self, show_labels: bool) -> Self {
+        self.show_labels = show_labels;
+        self
+    }
+
+    /// A [`Colorbar`] for the colormap and value range of the contours.
+    ///
+    /// Without a value range, the range of a function is only known once it is shown,
+    /// so the colorbar spans `0..=1`. Use [`Colorbar::for_item`] to follow what is shown.
+    pub fn colorbar(&self) -> Colorbar<'static> {
+        Colorbar::new(
+            self.colormap.clone().unwrap_or_default(),
+            self.color_range(&PlotBounds::NOTHING),
+        )
+    }
+
+    /// The value range the colormap spans when the plot shows the `visible` bounds.
+    fn color_range(&self, visible: &PlotBounds) -> RangeInclusive<f64> {
+        self.value_range.clone().unwrap_or_else(|| {
+            self.samples(visible)
+                .and_then(|samples| samples.value_range())
+                .map_or(0.0..=1.0, |(min, max)| min..=max)
+        })
+    }
+
+    /// The values of the field, sampled over `visible` bounds if it is a function.
+    fn samples(&self, visible: &PlotBounds) -> Option<SampledField> {
+        match &self.field {
+            ContourField::Cells {
+                values,
+                num_rows,
+                num_columns,
+                first_row_at_bottom,
+            } => Some(SampledField::from_cells(
+                values,
+                *num_rows,
+                *num_columns,
+                &self.extent,
+                *first_row_at_bottom,
+            )),
+            ContourField::Function {
+                function,
+                resolution,
+            } => visible
+                .is_valid()
+                .then(|| SampledField::from_fn(function, visible, resolution[0], resolution[1])),
+        }
+    }
+
+    /// The levels to draw, for values in `min..=max`.
+    fn levels_in(&self, (min, max): (f64, f64)) -> Vec<f64> {
+        if let Some(levels) = &self.levels {
+            let mut levels = levels.clone();
+            levels.sort_by(|a, b| a.total_cmp(b));
+            levels.dedup();
+            return levels;
+        }
+        let span = max - min;
+        if !(span.is_finite() && 0.0 < span) {
+            return Vec::new();
+        }
+        let step = crate::next_nice_step(&[1.0, 2.0, 5.0], span / self.num_levels.max(1) as f64);
+        let first = (min / step).floor() as i64 + 1;
+        let last = (max / step).ceil() as i64 - 1;
+        (first..=last).map(|i| i as f64 * step).collect()
+    }
+}
+
+impl PlotItem for Contour<'_> {
+    fn shapes(&self, ui: &Ui, transform: &PlotTransform, shapes: &mut Vec<Shape>) {
+        let Some(samples) = self.samples(transform.bounds()) else {
+            return;
+        };
+        let Some(data_range) = samples.value_range() else {
+            return;
+        };
+        let levels = self.levels_in(data_range);
+        let color_range = self
+            .value_range
+            .clone()
+            .unwrap_or(data_range.0..=data_range.1);
+        let level_color = |value: f64| match &self.colormap {
+            Some(colormap) => colormap.color_for_value(value, &color_range),
+            None => self.stroke.color,
+        };
+
+        if self.filled {
+            let colormap = self.colormap.clone().unwrap_or_default();
+            let bounds: Vec<f64> = std::iter::once(f64::NEG_INFINITY)
+                .chain(levels.iter().copied())
+                .chain([f64::INFINITY])
+                .collect();
+            let mut mesh = Mesh::default();
+            for band in bounds.windows(2) {
+                let middle = 0.5
+                    * (band[0].max(data_range.0).min(data_range.1)
+                        + band[1].min(data_range.1).max(data_range.0));
+                let color = colormap.color_for_value(middle, &color_range);
+                for polygon in samples.band(band[0], band[1]) {
+                    let index = mesh.vertices.len() as u32;
+                    for point in &polygon {
+                        mesh.colored_vertex(transform.position_from_point(point), color);
+                    }
+                    for i in 1..polygon.len() as u32 - 1 {
+                        mesh.add_triangle(index, index + i, index + i + 1);
+                    }
+                }
+            }
+            shapes.push(Shape::mesh(mesh));
+        }
+
+        let mut stroke = self.stroke;
+        if self.base.highlight {
+            stroke.width *= 2.0;
+        }
+        let font_id = TextStyle::Small.resolve(ui.style());
+        for &level in &levels {
+            let color = if self.filled {
+                stroke.color
+            } else {
+                level_color(level)
+            };
+            let stroke = Stroke::new(stroke.width, color);
+            let label = self.show_labels.then(|| {
+                let text = crate::format_number(level, 2);
+                ui.fonts(|f| f.layout_no_wrap(text, font_id.clone(), color))
+            });
+            for line in samples.isolines(level) {
+                let points: Vec<Pos2> = line
+                    .iter()
+                    .map(|point| transform.position_from_point(point))
+                    .collect();
+                let gap = label.as_ref().and_then(|galley| {
+                    cut_label_gap(&points, galley.size().x + 4.0).map(|gap| (galley, gap))
+                });
+                let Some((galley, (center, direction, pieces))) = gap else {
+                    shapes.push(Shape::line(points, stroke));
+                    continue;
+                };
+                for piece in pieces {
+                    shapes.push(Shape::line(piece, stroke));
+                }
+                // Keep the text upright:
+                let direction = if direction.x < 0.0 {
+                    -direction
+                } else {
+                    direction
+                };
+                let angle = direction.angle();
+                let offset = Rot2::from_angle(angle) * (0.5 * galley.size());
+                shapes.push(
+                    TextShape::new(center - offset, galley.clone(), color)
+                        .with_angle(angle)
+                        .into(),
+                );
+            }
+        }
+    }
+
+    fn initialize(&mut self, _x_range: RangeInclusive<f64>) {}
+
+    fn color(&self) -> Color32 {
+        match &self.colormap {
+            Some(colormap) => colormap.color_at(0.5),
+            None if self.filled => Colormap::default().color_at(0.5),
+            None => self.stroke.color,
+        }
+    }
+
+    fn color_mapping(&self, visible: &PlotBounds) -> Option<(Colormap, RangeInclusive<f64>)> {
+        let colormap = match &self.colormap {
+            Some(colormap) => colormap.clone(),
+            None if self.filled => Colormap::default(),
+            None => return None,
+        };
+        Some((colormap, self.color_range(visible)))
+    }
+
+    fn geometry(&self) -> PlotGeometry<'_> {
+        PlotGeometry::None
+    }
+
+    fn bounds(&self) -> PlotBounds {
+        self.extent
+    }
+
+    fn base(&self) -> &PlotItemBase {
+        &self.base
+    }
+
+    fn base_mut(&mut self) -> &mut PlotItemBase {
+        &mut self.base
+    }
+}
+
+#[test]
+fn test_contour_color_mapping() {
+    let slope = Contour::from_fn("slope", |x, y| 100.0 + x + y).colormap(Colormap::viridis());
+
+    // The colorbar spans the values of the function where the plot shows it:
+    let visible = PlotBounds::from_min_max([-1.0, -1.0], [1.0, 1.0]);
+    let (_, range) = slope.color_mapping(&visible).unwrap();
+    assert!((range.start() - 98.0).abs() < 0.1 && (range.end() - 102.0).abs() < 0.1);
+
+    let slope = slope.value_range(0.0..=200.0);
+    assert_eq!(slope.color_mapping(&visible).unwrap().1, 0.0..=200.0);
+
+    let grid = Contour::new("grid", vec![1.0, 2.0, 3.0, 4.0], 2, 2).filled(true);
+    assert_eq!(grid.color_mapping(&visible).unwrap().1, 1.0..=4.0);
+}
+
+// ----------------------------------------------------------------------------
+
 /// A bar chart.
 pub struct BarChart {
     base: PlotItemBase,
@@ -1398,6 +2758,146 @@ impl PlotItem for BarChart {
     }
 }
 
+/// A candlestick or OHLC chart of [`Candle`] elements, e.g. prices over time.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// use egui_plot::{Candle, CandleStyle, CandlestickChart, Plot};
+///
+/// let chart = CandlestickChart::new(
+///     "ACME",
+///     vec![
+///         Candle::new(1.0, 100.0, 104.0, 99.0, 103.0),
+///         Candle::new(2.0, 103.0, 103.5, 97.0, 98.0),
+///     ],
+/// )
+/// .style(CandleStyle::Hollow);
+/// Plot::new("prices").show(ui, |plot_ui| plot_ui.candlestick_chart(chart));
+/// # });
+/// ```
+pub struct CandlestickChart {
+    base: PlotItemBase,
+
+    pub(super) candles: Vec<Candle>,
+    pub(super) up_color: Color32,
+    pub(super) down_color: Color32,
+    pub(super) style: CandleStyle,
+
+    /// A custom element formatter
+    pub(super) element_formatter: Option<Box<dyn Fn(&Candle, &CandlestickChart) -> String>>,
+}
+
+impl CandlestickChart {
+    /// Create a chart of `candles`.
+    pub fn new(name: impl Into<String>, candles: Vec<Candle>) -> Self {
+        Self {
+            base: PlotItemBase::new(name.into()),
+            candles,
+            up_color: Color32::from_rgb(38, 166, 154),
+            down_color: Color32::from_rgb(239, 83, 80),
+            style: CandleStyle::default(),
+            element_formatter: None,
+        }
+    }
+
+    /// Color of candles that close at or above their open.
+    /// This is the color that shows up in the legend.
+    #[inline]
+    pub fn up_color(mut self, color: impl Into<Color32>) -> Self {
+        self.up_color = color.into();
+        self
+    }
+
+    /// Color of candles that close below their open.
+    #[inline]
+    pub fn down_color(mut self, color: impl Into<Color32>) -> Self {
+        self.down_color = color.into();
+        self
+    }
+
+    /// How to draw the candles. Default: [`CandleStyle::Filled`].
+    #[inline]
+    pub fn style(mut self, style: CandleStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Set the body width of all candles, in plot units, e.g. a bit less than the
+    /// duration of a time bucket.
+    #[inline]
+    pub fn width(mut self, width: f64) -> Self {
+        for candle in &mut self.candles {
+            candle.width = width;
+        }
+        self
+    }
+
+    /// Add a custom way to format an element.
+    /// Can be used to display a set number of decimals or custom labels.
+    #[inline]
+    pub fn element_formatter(mut self, formatter: Box<dyn Fn(&Candle, &Self) -> String>) -> Self {
+        self.element_formatter = Some(formatter);
+        self
+    }
+
+    builder_methods_for_base!();
+}
+
+impl PlotItem for CandlestickChart {
+    fn shapes(&self, _ui: &Ui, transform: &PlotTransform, shapes: &mut Vec<Shape>) {
+        for candle in &self.candles {
+            candle.add_shapes(self, transform, self.base.highlight, shapes);
+        }
+    }
+
+    fn initialize(&mut self, _x_range: RangeInclusive<f64>) {
+        // nothing to do
+    }
+
+    fn color(&self) -> Color32 {
+        self.up_color
+    }
+
+    fn geometry(&self) -> PlotGeometry<'_> {
+        PlotGeometry::Rects
+    }
+
+    fn bounds(&self) -> PlotBounds {
+        let mut bounds = PlotBounds::NOTHING;
+        for candle in &self.candles {
+            bounds.merge(&candle.bounds());
+        }
+        bounds
+    }
+
+    fn find_closest(&self, point: Pos2, transform: &PlotTransform) -> Option<ClosestElem> {
+        find_closest_rect(&self.candles, point, transform)
+    }
+
+    fn on_hover(
+        &self,
+        _plot_area_response: &egui::Response,
+        elem: ClosestElem,
+        shapes: &mut Vec<Shape>,
+        cursors: &mut Vec<Cursor>,
+        plot: &PlotConfig<'_>,
+        _: &LabelFormatter<'_>,
+    ) {
+        let candle = &self.candles[elem.index];
+
+        candle.add_shapes(self, plot.transform, true, shapes);
+        candle.add_rulers_and_text(self, plot, shapes, cursors);
+    }
+
+    fn base(&self) -> &PlotItemBase {
+        &self.base
+    }
+
+    fn base_mut(&mut self) -> &mut PlotItemBase {
+        &mut self.base
+    }
+}
+
 /// A diagram containing a series of [`BoxElem`] elements.
 pub struct BoxPlot {
     base: PlotItemBase,
@@ -1635,11 +3135,38 @@ fn add_rulers_and_text(
 /// Draws a cross of horizontal and vertical ruler at the `pointer` position,
 /// and a label describing the coordinate.
 ///
-/// `value` is used to for text displaying X/Y coordinates.
+/// `value` is used to for text displaying X/Y coordinates,
+/// followed by the `[lower, upper]` `errors` along x and y, if any.
 #[allow(clippy::too_many_arguments)]
 pub(super) fn rulers_and_tooltip_at_value(
     plot_area_response: &egui::Response,
     value: PlotPoint,
+    errors: [Option<[f64; 2]>; 2],
+    name: &str,
+    plot: &PlotConfig<'_>,
+    cursors: &mut Vec<Cursor>,
+    label_formatter: &LabelFormatter<'_>,
+) {
+    rulers_and_tooltip_with_details(
+        plot_area_response,
+        value,
+        errors,
+        "",
+        name,
+        plot,
+        cursors,
+        label_formatter,
+    );
+}
+
+/// Like [`rulers_and_tooltip_at_value`], with `details` on the lines after the coordinates
+/// of the default label.
+#[allow(clippy::too_many_arguments)]
+pub(super) fn rulers_and_tooltip_with_details(
+    plot_area_response: &egui::Response,
+    value: PlotPoint,
+    errors: [Option<[f64; 2]>; 2],
+    details: &str,
     name: &str,
     plot: &PlotConfig<'_>,
     cursors: &mut Vec<Cursor>,
@@ -1660,24 +3187,83 @@ pub(super) fn rulers_and_tooltip_at_value(
         } else {
             format!("{name}\n")
         };
-        let scale = plot.transform.dvalue_dpos();
-        let x_decimals = ((-scale[0].abs().log10()).ceil().at_least(0.0) as usize).clamp(1, 6);
-        let y_decimals = ((-scale[1].abs().log10()).ceil().at_least(0.0) as usize).clamp(1, 6);
-        if plot.show_x && plot.show_y {
+        let details = if details.is_empty() {
+            String::new()
+        } else {
+            format!("\n{details}")
+        };
+        if let Some(polar) = plot.transform.polar() {
+            let radius_step = plot.transform.dvalue_dpos()[0].abs();
+            let radius_decimals =
+                ((-radius_step.log10()).ceil().at_least(0.0) as usize).clamp(1, 6);
+            let angle_decimals = if polar.full_turn() < 10.0 { 3 } else { 1 };
             format!(
-                "{}x = {:.*}\ny = {:.*}",
-                prefix, x_decimals, value.x, y_decimals, value.y
+                "{}θ = {}\nr = {:.*}{}{details}",
+                prefix,
+                polar.format_angle(value.x, angle_decimals),
+                radius_decimals,
+                value.y,
+                format_error(errors[1], radius_decimals)
             )
-        } else if plot.show_x {
-            format!("{}x = {:.*}", prefix, x_decimals, value.x)
-        } else if plot.show_y {
-            format!("{}y = {:.*}", prefix, y_decimals, value.y)
         } else {
-            unreachable!()
+            let [x_decimals, y_decimals] = value_decimals(plot.transform, &value);
+            let x = format!(
+                "x = {:.*}{}",
+                x_decimals,
+                value.x,
+                format_error(errors[0], x_decimals)
+            );
+            let y = format!(
+                "y = {:.*}{}",
+                y_decimals,
+                value.y,
+                format_error(errors[1], y_decimals)
+            );
+            if plot.show_x && plot.show_y {
+                format!("{prefix}{x}\n{y}{details}")
+            } else if plot.show_x {
+                format!("{prefix}{x}{details}")
+            } else if plot.show_y {
+                format!("{prefix}{y}{details}")
+            } else {
+                unreachable!()
+            }
         }
     };
 
-    // We show the tooltip as soon as we're hovering the plot area:
+    show_tooltip(plot_area_response, text);
+}
+
+/// How many decimals to show of the x and y of `value` in a hover label,
+/// so they change about one digit per ui point at `value`.
+pub(super) fn value_decimals(transform: &PlotTransform, value: &PlotPoint) -> [usize; 2] {
+    transform
+        .dvalue_dpos_at(value)
+        .map(|scale| ((-scale.abs().log10()).ceil().at_least(0.0) as usize).clamp(1, 6))
+}
+
+/// An error for the hover label, e.g. ` ± 0.10`, or ` −0.10 +0.20` if it is asymmetric.
+fn format_error(error: Option<[f64; 2]>, num_decimals: usize) -> String {
+    match error {
+        None => String::new(),
+        Some([lower, upper]) if lower == upper => format!(" ± {upper:.num_decimals$}"),
+        Some([lower, upper]) => format!(" −{lower:.num_decimals$} +{upper:.num_decimals$}"),
+    }
+}
+
+/// The range of the finite `values`, or `0..=1` if there are none.
+fn finite_range(values: &[f64]) -> RangeInclusive<f64> {
+    let (min, max) = values
+        .iter()
+        .filter(|value| value.is_finite())
+        .fold((f64::INFINITY, -f64::INFINITY), |(min, max), &value| {
+            (min.min(value), max.max(value))
+        });
+    if min <= max { min..=max } else { 0.0..=1.0 }
+}
+
+/// Shows `text` in a tooltip next to the pointer, for as long as the plot area is hovered.
+fn show_tooltip(plot_area_response: &egui::Response, text: String) {
     let mut tooltip = egui::Tooltip::new(
         plot_area_response.id,
         plot_area_response.ctx.clone(),
@@ -1695,6 +3281,22 @@ pub(super) fn rulers_and_tooltip_at_value(
     });
 }
 
+fn find_closest_point(
+    points: &[PlotPoint],
+    point: Pos2,
+    transform: &PlotTransform,
+) -> Option<ClosestElem> {
+    points
+        .iter()
+        .enumerate()
+        .map(|(index, value)| {
+            let pos = transform.position_from_point(value);
+            let dist_sq = point.distance_sq(pos);
+            ClosestElem { index, dist_sq }
+        })
+        .min_by_key(|e| e.dist_sq.ord())
+}
+
 fn find_closest_rect<'a, T>(
     rects: impl IntoIterator<Item = &'a T>,
     point: Pos2,
@@ -0,0 +1,408 @@
+use std::ops::RangeInclusive;
+
+use egui::{Color32, Mesh, NumExt as _, Pos2, Rgba, Shape, Stroke, Ui};
+use emath::Float as _;
+
+use super::{
+    ClosestElem, Cursor, LabelFormatter, LineStyle, PlotBounds, PlotConfig, PlotGeometry, PlotItem,
+    PlotItemBase, PlotPoint, PlotTransform, rulers_and_tooltip_with_details, rulers_color,
+    value_decimals, y_at,
+};
+
+/// Where the bottom layer of [`StackedAreas`] starts, and how the layers are scaled.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum StackOffset {
+    /// The bottom layer starts at zero.
+    #[default]
+    Zero,
+
+    /// Like [`Self::Zero`], but the layers are scaled so they always add up to 100,
+    /// i.e. each layer shows its share of the total in percent.
+    Normalized,
+
+    /// A streamgraph centered around zero.
+    Silhouette,
+
+    /// A streamgraph whose baseline is chosen to make the layers wiggle as little as possible.
+    Wiggle,
+}
+
+/// Several series stacked on top of each other, each filling the area from the top of
+/// the one below it, so the top of the stack shows their total.
+///
+/// The layers are sampled at all x values of any layer, interpolating linearly between the
+/// points of each layer. A layer is zero outside of its own x range.
+/// Each layer is a separate item with its own legend entry.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// use egui_plot::{Plot, StackOffset, StackedAreas};
+///
+/// let areas = StackedAreas::new()
+///     .layer("Solar", vec![[2000.0, 1.0], [2010.0, 30.0], [2020.0, 800.0]])
+///     .layer("Wind", vec![[2000.0, 31.0], [2010.0, 340.0], [2020.0, 1590.0]])
+///     .offset(StackOffset::Normalized);
+/// Plot::new("energy").show(ui, |plot_ui| plot_ui.stacked_areas(areas));
+/// # });
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct StackedAreas {
+    layers: Vec<(String, Vec<PlotPoint>)>,
+    colors: Vec<Color32>,
+    x_values: Option<Vec<f64>>,
+    offset: StackOffset,
+    fill_alpha: Option<f32>,
+    stroke_width: Option<f32>,
+}
+
+impl StackedAreas {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a layer on top of the previous ones. The points should be sorted by x.
+    pub fn layer(
+        mut self,
+        name: impl Into<String>,
+        points: impl IntoIterator<Item = impl Into<PlotPoint>>,
+    ) -> Self {
+        let points = points.into_iter().map(Into::into).collect();
+        self.layers.push((name.into(), points));
+        self
+    }
+
+    /// Colors of the layers, from the bottom up.
+    /// Layers without a color get an automatic one.
+    #[inline]
+    pub fn colors(mut self, colors: impl IntoIterator<Item = impl Into<Color32>>) -> Self {
+        self.colors = colors.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Sample the layers at these x values instead of at the x values of all their points.
+    ///
+    /// They are sorted, and duplicate and non-finite values are left out.
+    #[inline]
+    pub fn x_values(mut self, x_values: impl Into<Vec<f64>>) -> Self {
+        self.x_values = Some(sorted_x_values(x_values.into()));
+        self
+    }
+
+    /// Where the stack starts. Default: [`StackOffset::Zero`].
+    #[inline]
+    pub fn offset(mut self, offset: StackOffset) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    /// Set the alpha channel of the layers' fill. Default is `0.5`.
+    #[inline]
+    pub fn fill_alpha(mut self, alpha: impl Into<f32>) -> Self {
+        self.fill_alpha = Some(alpha.into());
+        self
+    }
+
+    /// Width of the line along the top of each layer. Default is `1.0`.
+    #[inline]
+    pub fn stroke_width(mut self, width: impl Into<f32>) -> Self {
+        self.stroke_width = Some(width.into());
+        self
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.layers.iter().all(|(_, points)| points.is_empty())
+    }
+
+    /// One plot item per layer, from the bottom up.
+    pub(crate) fn into_layers(self, mut auto_color: impl FnMut() -> Color32) -> Vec<StackedLayer> {
+        let x_values = self.x_values.unwrap_or_else(|| {
+            sorted_x_values(
+                self.layers
+                    .iter()
+                    .flat_map(|(_, points)| points.iter().map(|point| point.x))
+                    .collect(),
+            )
+        });
+        let values: Vec<Vec<f64>> = self
+            .layers
+            .iter()
+            .map(|(_, points)| {
+                x_values
+                    .iter()
+                    .map(|&x| y_at(points, x).unwrap_or(0.0))
+                    .collect()
+            })
+            .collect();
+        let edges = stack(&values, self.offset);
+
+        let mut totals = vec![0.0; x_values.len()];
+        self.layers
+            .into_iter()
+            .zip(values)
+            .enumerate()
+            .map(|(index, ((name, _), values))| {
+                for (total, value) in totals.iter_mut().zip(&values) {
+                    *total += value;
+                }
+                StackedLayer {
+                    base: PlotItemBase::new(name),
+                    color: self
+                        .colors
+                        .get(index)
+                        .copied()
+                        .unwrap_or_else(&mut auto_color),
+                    x_values: x_values.clone(),
+                    lower: edges[index].clone(),
+                    upper: edges[index + 1].clone(),
+                    values,
+                    totals: totals.clone(),
+                    normalized: self.offset == StackOffset::Normalized,
+                    fill_alpha: self.fill_alpha.unwrap_or(0.5),
+                    stroke_width: self.stroke_width.unwrap_or(1.0),
+                }
+            })
+            .collect()
+    }
+}
+
+/// The finite `x_values`, sorted and without duplicates.
+fn sorted_x_values(mut x_values: Vec<f64>) -> Vec<f64> {
+    x_values.retain(|x| x.is_finite());
+    x_values.sort_by(f64::total_cmp);
+    x_values.dedup();
+    x_values
+}
+
+/// The edges between stacked layers: the baseline, followed by the top of each layer.
+fn stack(values: &[Vec<f64>], offset: StackOffset) -> Vec<Vec<f64>> {
+    let num_x = values.first().map_or(0, Vec::len);
+    let totals: Vec<f64> = (0..num_x)
+        .map(|j| values.iter().map(|layer| layer[j]).sum())
+        .collect();
+
+    let scale = |j: usize| -> f64 {
+        if offset == StackOffset::Normalized {
+            if totals[j] == 0.0 {
+                0.0
+            } else {
+                100.0 / totals[j]
+            }
+        } else {
+            1.0
+        }
+    };
+
+    let baseline: Vec<f64> = match offset {
+        StackOffset::Zero | StackOffset::Normalized => vec![0.0; num_x],
+        StackOffset::Silhouette => totals.iter().map(|total| -0.5 * total).collect(),
+        StackOffset::Wiggle => {
+            // Byron & Wattenberg: move the baseline against the weighted average slope
+            // of the middle of each layer.
+            let mut baseline = vec![0.0; num_x];
+            for j in 1..num_x {
+                let mut below_change = 0.0;
+                let mut weighted_change = 0.0;
+                for layer in values {
+                    let change = layer[j] - layer[j - 1];
+                    weighted_change += (below_change + 0.5 * change) * layer[j];
+                    below_change += change;
+                }
+                baseline[j] = baseline[j - 1];
+                if totals[j] != 0.0 {
+                    baseline[j] -= weighted_change / totals[j];
+                }
+            }
+            baseline
+        }
+    };
+
+    let mut edges = vec![baseline];
+    for layer in values {
+        let below = &edges[edges.len() - 1];
+        let top = (0..num_x).map(|j| below[j] + scale(j) * layer[j]).collect();
+        edges.push(top);
+    }
+    edges
+}
+
+/// One layer of [`StackedAreas`].
+pub(crate) struct StackedLayer {
+    base: PlotItemBase,
+    pub(crate) color: Color32,
+    x_values: Vec<f64>,
+    lower: Vec<f64>,
+    upper: Vec<f64>,
+    values: Vec<f64>,
+    totals: Vec<f64>,
+    normalized: bool,
+    fill_alpha: f32,
+    stroke_width: f32,
+}
+
+impl StackedLayer {
+    fn upper_point(&self, index: usize) -> PlotPoint {
+        PlotPoint::new(self.x_values[index], self.upper[index])
+    }
+}
+
+impl PlotItem for StackedLayer {
+    fn shapes(&self, _ui: &Ui, transform: &PlotTransform, shapes: &mut Vec<Shape>) {
+        let mut fill_alpha = self.fill_alpha;
+        if self.base.highlight {
+            fill_alpha = (2.0 * fill_alpha).at_most(1.0);
+        }
+        let fill: Color32 = Rgba::from(self.color)
+            .to_opaque()
+            .multiply(fill_alpha)
+            .into();
+
+        let position = |x: f64, y: f64| transform.position_from_point(&PlotPoint::new(x, y));
+        let mut mesh = Mesh::default();
+        for j in 1..self.x_values.len() {
+            let (x0, x1) = (self.x_values[j - 1], self.x_values[j]);
+            let index = mesh.vertices.len() as u32;
+            for pos in [
+                position(x0, self.lower[j - 1]),
+                position(x1, self.lower[j]),
+                position(x1, self.upper[j]),
+                position(x0, self.upper[j - 1]),
+            ] {
+                mesh.colored_vertex(pos, fill);
+            }
+            mesh.add_triangle(index, index + 1, index + 2);
+            mesh.add_triangle(index, index + 2, index + 3);
+        }
+        shapes.push(Shape::mesh(mesh));
+
+        if 0.0 < self.stroke_width {
+            let points = (0..self.x_values.len())
+                .map(|j| transform.position_from_point(&self.upper_point(j)))
+                .collect();
+            let stroke = Stroke::new(self.stroke_width, self.color);
+            LineStyle::Solid.style_line(points, stroke, self.base.highlight, shapes);
+        }
+    }
+
+    fn initialize(&mut self, _x_range: RangeInclusive<f64>) {}
+
+    fn color(&self) -> Color32 {
+        self.color
+    }
+
+    fn geometry(&self) -> PlotGeometry<'_> {
+        // The samples are in `x_values` and `upper`, so hovering has its own `find_closest`:
+        PlotGeometry::None
+    }
+
+    fn bounds(&self) -> PlotBounds {
+        let mut bounds = PlotBounds::NOTHING;
+        for (j, &x) in self.x_values.iter().enumerate() {
+            bounds.extend_with(&PlotPoint::new(x, self.lower[j]));
+            bounds.extend_with(&PlotPoint::new(x, self.upper[j]));
+        }
+        bounds
+    }
+
+    fn find_closest(&self, point: Pos2, transform: &PlotTransform) -> Option<ClosestElem> {
+        // Inside the layer counts as a direct hit on the nearest sample:
+        let value = transform.value_from_position(point);
+        let next = self.x_values.partition_point(|&x| x < value.x);
+        if 0 < next && next < self.x_values.len() {
+            let (x0, x1) = (self.x_values[next - 1], self.x_values[next]);
+            let t = (value.x - x0) / (x1 - x0);
+            let lower = self.lower[next - 1] + t * (self.lower[next] - self.lower[next - 1]);
+            let upper = self.upper[next - 1] + t * (self.upper[next] - self.upper[next - 1]);
+            if lower.min(upper) <= value.y && value.y <= lower.max(upper) {
+                let index = if t < 0.5 { next - 1 } else { next };
+                return Some(ClosestElem {
+                    index,
+                    dist_sq: 0.0,
+                });
+            }
+        }
+
+        (0..self.x_values.len())
+            .map(|index| ClosestElem {
+                index,
+                dist_sq: point.distance_sq(transform.position_from_point(&self.upper_point(index))),
+            })
+            .min_by_key(|elem| elem.dist_sq.ord())
+    }
+
+    fn on_hover(
+        &self,
+        plot_area_response: &egui::Response,
+        elem: ClosestElem,
+        shapes: &mut Vec<Shape>,
+        cursors: &mut Vec<Cursor>,
+        plot: &PlotConfig<'_>,
+        label_formatter: &LabelFormatter<'_>,
+    ) {
+        let value = self.upper_point(elem.index);
+        let pointer = plot.transform.position_from_point(&value);
+        shapes.push(Shape::circle_filled(pointer, 3.0, rulers_color(plot.ui)));
+
+        // Below the coordinates of the top of the layer, the label shows what it adds to the stack:
+        let [_, decimals] = value_decimals(plot.transform, &value);
+        let share = if self.normalized {
+            let share = self.upper[elem.index] - self.lower[elem.index];
+            format!(" ({share:.1}%)")
+        } else {
+            String::new()
+        };
+        let details = format!(
+            "value = {:.decimals$}{share}\ntotal = {:.decimals$}",
+            self.values[elem.index], self.totals[elem.index],
+        );
+        rulers_and_tooltip_with_details(
+            plot_area_response,
+            value,
+            [None, None],
+            &details,
+            self.name(),
+            plot,
+            cursors,
+            label_formatter,
+        );
+    }
+
+    fn base(&self) -> &PlotItemBase {
+        &self.base
+    }
+
+    fn base_mut(&mut self) -> &mut PlotItemBase {
+        &mut self.base
+    }
+}
+
+#[test]
+fn test_stack() {
+    let values = vec![vec![1.0, 2.0], vec![3.0, 2.0]];
+    assert_eq!(
+        stack(&values, StackOffset::Zero),
+        [[0.0, 0.0], [1.0, 2.0], [4.0, 4.0]]
+    );
+    assert_eq!(
+        stack(&values, StackOffset::Normalized),
+        [[0.0, 0.0], [25.0, 50.0], [100.0, 100.0]]
+    );
+    assert_eq!(
+        stack(&values, StackOffset::Silhouette),
+        [[-2.0, -2.0], [-1.0, 0.0], [2.0, 2.0]]
+    );
+
+    // The layers are sampled at the x values of both, and are zero outside their range:
+    let layers = StackedAreas::new()
+        .layer("a", [[0.0, 1.0], [2.0, 3.0]])
+        .layer("b", [[1.0, 1.0], [3.0, 1.0]])
+        .into_layers(|| Color32::RED);
+    assert_eq!(layers[1].x_values, [0.0, 1.0, 2.0, 3.0]);
+    assert_eq!(layers[1].upper, [1.0, 3.0, 4.0, 1.0]);
+    assert_eq!(layers[1].totals, layers[1].upper);
+
+    let layers = StackedAreas::new()
+        .layer("a", [[0.0, 1.0], [2.0, 3.0]])
+        .x_values(vec![2.0, f64::NAN, 0.0, 2.0])
+        .into_layers(|| Color32::RED);
+    assert_eq!(layers[0].x_values, [0.0, 2.0]);
+}
@@ -2,7 +2,10 @@ use std::ops::{Bound, RangeBounds, RangeInclusive};
 
 use egui::{Pos2, Shape, Stroke, Vec2, lerp};
 
-use crate::transform::PlotBounds;
+use crate::{
+    Axis,
+    transform::{PlotBounds, PlotTransform},
+};
 
 /// A point coordinate in the plot.
 ///
@@ -136,6 +139,55 @@ impl std::fmt::Display for LineStyle {
 
 // ----------------------------------------------------------------------------
 
+/// Where a line drawn as steps changes its value between two points.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum StepMode {
+    /// At the previous point, so each value holds up to and including its point.
+    Pre,
+
+    /// At the next point, so each value holds from its point on, like a counter.
+    Post,
+
+    /// Halfway between the points.
+    Mid,
+}
+
+impl StepMode {
+    /// The corners of the staircase through `points`, each with the index of the point
+    /// whose value it has.
+    pub(super) fn corners(self, points: &[PlotPoint]) -> Vec<(PlotPoint, usize)> {
+        let mut corners = Vec::with_capacity(3 * points.len());
+        for (index, point) in points.iter().enumerate() {
+            if let Some(previous) = index.checked_sub(1).map(|previous| points[previous]) {
+                match self {
+                    Self::Pre => corners.push((PlotPoint::new(previous.x, point.y), index)),
+                    Self::Post => corners.push((PlotPoint::new(point.x, previous.y), index - 1)),
+                    Self::Mid => {
+                        let x = 0.5 * (previous.x + point.x);
+                        corners.push((PlotPoint::new(x, previous.y), index - 1));
+                        corners.push((PlotPoint::new(x, point.y), index));
+                    }
+                }
+            }
+            corners.push((*point, index));
+        }
+        corners
+    }
+}
+
+impl std::fmt::Display for StepMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Pre => write!(f, "Pre"),
+            Self::Post => write!(f, "Post"),
+            Self::Mid => write!(f, "Mid"),
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------
+
 /// Determines whether a plot element is vertically or horizontally oriented.
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub enum Orientation {
@@ -335,6 +387,79 @@ impl<'a> PlotPoints<'a> {
             }
         }
     }
+
+    /// The bounds of the points whose values along `axis` are in `range`,
+    /// e.g. of the points in the visible x range.
+    ///
+    /// This uses a binary search if the points are sorted along `axis`, as is common for x.
+    /// A generator counts as a whole, since its points haven't been generated yet.
+    pub fn bounds_in_range(&self, axis: Axis, range: &RangeInclusive<f64>) -> PlotBounds {
+        match self {
+            Self::Generator(generator) => generator.estimate_bounds(),
+            Self::Owned(_) | Self::Borrowed(_) => {
+                let points = self.points();
+                let sorted = points.is_sorted_by(|a, b| axis_value(a, axis) <= axis_value(b, axis));
+                points_bounds_in_range(points, axis, range, sorted, |bounds, _, point| {
+                    bounds.extend_with(point);
+                })
+            }
+        }
+    }
+}
+
+fn axis_value(point: &PlotPoint, axis: Axis) -> f64 {
+    match axis {
+        Axis::X => point.x,
+        Axis::Y => point.y,
+    }
+}
+
+/// The bounds that `add_point` extends with each of the `points` whose values along `axis`
+/// are in `range`, given the index of the point.
+///
+/// If the caller knows the points are `sorted` along `axis`, the ones in range are found
+/// with a binary search.
+pub(crate) fn points_bounds_in_range(
+    points: &[PlotPoint],
+    axis: Axis,
+    range: &RangeInclusive<f64>,
+    sorted: bool,
+    add_point: impl Fn(&mut PlotBounds, usize, &PlotPoint),
+) -> PlotBounds {
+    let value = |point: &PlotPoint| axis_value(point, axis);
+
+    // Skip straight to the points in range if we can:
+    let (offset, points) = if sorted {
+        let start = points.partition_point(|point| value(point) < *range.start());
+        let end = points.partition_point(|point| value(point) <= *range.end());
+        (start, &points[start..end.max(start)])
+    } else {
+        (0, points)
+    };
+
+    let mut bounds = PlotBounds::NOTHING;
+    for (index, point) in points.iter().enumerate() {
+        if range.contains(&value(point)) {
+            add_point(&mut bounds, offset + index, point);
+        }
+    }
+    bounds
+}
+
+#[test]
+fn test_points_bounds_in_range() {
+    let sorted: PlotPoints<'_> = (0..100)
+        .map(|i| [f64::from(i), f64::from(i % 10)])
+        .collect();
+    let bounds = sorted.bounds_in_range(Axis::X, &(20.5..=23.0));
+    assert_eq!((bounds.min, bounds.max), ([21.0, 1.0], [23.0, 3.0]));
+
+    let unsorted = PlotPoints::new(vec![[3.0, 30.0], [1.0, 10.0], [2.0, 20.0], [9.0, 90.0]]);
+    let bounds = unsorted.bounds_in_range(Axis::X, &(1.5..=3.0));
+    assert_eq!((bounds.min, bounds.max), ([2.0, 20.0], [3.0, 30.0]));
+
+    let bounds = unsorted.bounds_in_range(Axis::Y, &(15.0..=95.0));
+    assert_eq!((bounds.min, bounds.max), ([2.0, 20.0], [9.0, 90.0]));
 }
 
 // ----------------------------------------------------------------------------
@@ -444,6 +569,159 @@ impl ExplicitGenerator<'_> {
 
 // ----------------------------------------------------------------------------
 
+/// Error margins of a series of points, along x and/or y.
+///
+/// Each error is a `[lower, upper]` pair of distances below and above the point.
+/// Points without an error, e.g. because fewer errors than points were given, get no error bar.
+///
+/// Used by [`super::ErrorBars`], [`super::Points::error_bars`] and [`super::Line::error_bars`].
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct PointErrors {
+    x: Vec<[f64; 2]>,
+    y: Vec<[f64; 2]>,
+}
+
+impl PointErrors {
+    /// No errors yet; add them with [`Self::x`], [`Self::y`] and their asymmetric versions.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The same error below and above each point, along x.
+    #[inline]
+    pub fn x(mut self, errors: impl IntoIterator<Item = f64>) -> Self {
+        self.x = symmetric(errors);
+        self
+    }
+
+    /// Separate errors to the left (`lower`) and right (`upper`) of each point.
+    #[inline]
+    pub fn x_asymmetric(
+        mut self,
+        lower: impl IntoIterator<Item = f64>,
+        upper: impl IntoIterator<Item = f64>,
+    ) -> Self {
+        self.x = asymmetric(lower, upper);
+        self
+    }
+
+    /// The same error below and above each point, along y.
+    #[inline]
+    pub fn y(mut self, errors: impl IntoIterator<Item = f64>) -> Self {
+        self.y = symmetric(errors);
+        self
+    }
+
+    /// Separate errors below (`lower`) and above (`upper`) each point.
+    #[inline]
+    pub fn y_asymmetric(
+        mut self,
+        lower: impl IntoIterator<Item = f64>,
+        upper: impl IntoIterator<Item = f64>,
+    ) -> Self {
+        self.y = asymmetric(lower, upper);
+        self
+    }
+
+    /// Are there no errors at all?
+    pub fn is_empty(&self) -> bool {
+        self.x.is_empty() && self.y.is_empty()
+    }
+
+    /// The `[lower, upper]` errors of the point at `index`, along x and y.
+    pub fn at(&self, index: usize) -> [Option<[f64; 2]>; 2] {
+        [self.x.get(index).copied(), self.y.get(index).copied()]
+    }
+
+    /// The bounds of `points` extended by their errors.
+    pub(crate) fn bounds(&self, points: &[PlotPoint]) -> PlotBounds {
+        let mut bounds = PlotBounds::NOTHING;
+        for (index, point) in points.iter().enumerate() {
+            bounds.extend_with(point);
+            let [x, y] = self.at(index);
+            if let Some([lower, upper]) = x {
+                bounds.extend_with_x(point.x - lower);
+                bounds.extend_with_x(point.x + upper);
+            }
+            if let Some([lower, upper]) = y {
+                bounds.extend_with_y(point.y - lower);
+                bounds.extend_with_y(point.y + upper);
+            }
+        }
+        bounds
+    }
+
+    /// Error bars with caps `cap_width` wide (in ui points) for each of the `points`.
+    ///
+    /// Ends that the axis scale can't show, like those below zero on a log axis,
+    /// are cut off at the edge of the plot, without a cap.
+    pub(crate) fn shapes(
+        &self,
+        points: &[PlotPoint],
+        transform: &PlotTransform,
+        stroke: Stroke,
+        cap_width: f32,
+        shapes: &mut Vec<Shape>,
+    ) {
+        let half_cap = 0.5 * cap_width;
+        let bounds = transform.bounds();
+        for (index, point) in points.iter().enumerate() {
+            for (axis, error) in self.at(index).into_iter().enumerate() {
+                let Some([lower, upper]) = error else {
+                    continue;
+                };
+                let scale = &transform.scales()[axis];
+                let (value, cap_offset) = if axis == 0 {
+                    (point.x, Vec2::new(0.0, half_cap))
+                } else {
+                    (point.y, Vec2::new(half_cap, 0.0))
+                };
+                let ends = [
+                    (value - lower, bounds.min[axis]),
+                    (value + upper, bounds.max[axis]),
+                ]
+                .map(|(end, edge)| {
+                    let has_cap = scale.is_valid(end);
+                    let end = if has_cap { end } else { edge };
+                    let end = if axis == 0 {
+                        PlotPoint::new(end, point.y)
+                    } else {
+                        PlotPoint::new(point.x, end)
+                    };
+                    (transform.position_from_point(&end), has_cap)
+                });
+                shapes.push(Shape::line_segment([ends[0].0, ends[1].0], stroke));
+                for (end, has_cap) in ends {
+                    if has_cap {
+                        let cap = [end - cap_offset, end + cap_offset];
+                        shapes.push(Shape::line_segment(cap, stroke));
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn symmetric(errors: impl IntoIterator<Item = f64>) -> Vec<[f64; 2]> {
+    errors
+        .into_iter()
+        .map(|error| [error.abs(), error.abs()])
+        .collect()
+}
+
+fn asymmetric(
+    lower: impl IntoIterator<Item = f64>,
+    upper: impl IntoIterator<Item = f64>,
+) -> Vec<[f64; 2]> {
+    lower
+        .into_iter()
+        .zip(upper)
+        .map(|(lower, upper)| [lower.abs(), upper.abs()])
+        .collect()
+}
+
+// ----------------------------------------------------------------------------
+
 /// Result of [`super::PlotItem::find_closest()`] search, identifies an element inside the item for immediate use
 pub struct ClosestElem {
     /// Position of hovered-over value (or bar/box-plot/…) in `PlotItem`
@@ -452,3 +730,44 @@ pub struct ClosestElem {
     /// Squared distance from the mouse cursor (needed to compare against other `PlotItems`, which might be nearer)
     pub dist_sq: f32,
 }
+
+#[test]
+fn test_step_corners() {
+    let points = [PlotPoint::new(0.0, 1.0), PlotPoint::new(2.0, 3.0)];
+    let corners = |mode: StepMode| -> Vec<([f64; 2], usize)> {
+        mode.corners(&points)
+            .into_iter()
+            .map(|(corner, index)| ([corner.x, corner.y], index))
+            .collect()
+    };
+    assert_eq!(
+        corners(StepMode::Pre),
+        [([0.0, 1.0], 0), ([0.0, 3.0], 1), ([2.0, 3.0], 1)]
+    );
+    assert_eq!(
+        corners(StepMode::Post),
+        [([0.0, 1.0], 0), ([2.0, 1.0], 0), ([2.0, 3.0], 1)]
+    );
+    assert_eq!(
+        corners(StepMode::Mid),
+        [
+            ([0.0, 1.0], 0),
+            ([1.0, 1.0], 0),
+            ([1.0, 3.0], 1),
+            ([2.0, 3.0], 1)
+        ]
+    );
+}
+
+#[test]
+fn test_point_errors_bounds() {
+    let points = [PlotPoint::new(1.0, 2.0), PlotPoint::new(3.0, 4.0)];
+    let errors = PointErrors::new()
+        .x([0.5])
+        .y_asymmetric([1.0, -1.0], [0.5, 2.0]);
+    assert_eq!(errors.at(1), [None, Some([1.0, 2.0])]);
+
+    let bounds = errors.bounds(&points);
+    assert_eq!(bounds.min(), [0.5, 1.0]);
+    assert_eq!(bounds.max(), [3.0, 6.0]);
+}
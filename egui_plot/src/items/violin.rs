@@ -0,0 +1,561 @@
+use std::ops::RangeInclusive;
+
+use egui::{Color32, Id, Mesh, NumExt as _, Pos2, Shape, Stroke, Ui};
+
+use super::{
+    BoxSpread, ClosestElem, Cursor, LabelFormatter, Orientation, PlotBounds, PlotConfig,
+    PlotGeometry, PlotItem, PlotItemBase, PlotPoint, PlotTransform, RectElement,
+    add_rulers_and_text, builder_methods_for_base, find_closest_rect, highlighted_color,
+    histogram::quantile,
+};
+
+/// How many values the density of a violin is estimated at.
+const NUM_DENSITY_POINTS: usize = 100;
+
+/// How far the density of a violin extends beyond the smallest and largest sample,
+/// in bandwidths.
+const DENSITY_CUT: f64 = 2.0;
+
+/// Which side of its argument a [`ViolinElem`] is drawn on.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ViolinSide {
+    /// A symmetric violin on both sides.
+    #[default]
+    Both,
+
+    /// Only the half below the argument, i.e. on the left of a vertical violin.
+    Low,
+
+    /// Only the half above the argument, i.e. on the right of a vertical violin.
+    High,
+}
+
+/// A violin in a [`ViolinPlot`]: a kernel density estimate of samples, mirrored around
+/// its argument.
+///
+/// Two groups can be compared with split violins, by putting one at [`ViolinSide::Low`] and
+/// the other at [`ViolinSide::High`] of the same argument.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ViolinElem {
+    name: String,
+    orientation: Orientation,
+    argument: f64,
+    samples: Vec<f64>,
+    bandwidth: f64,
+    spread: BoxSpread,
+    density: Vec<[f64; 2]>,
+    width: f64,
+    side: ViolinSide,
+    show_box: bool,
+    box_width: f64,
+    stroke: Stroke,
+    fill: Color32,
+}
+
+impl ViolinElem {
+    /// A violin of `samples` at `argument`. Samples that are not finite are ignored.
+    ///
+    /// The density is estimated with a gaussian kernel, whose bandwidth is chosen with
+    /// Silverman's rule of thumb. Its `orientation` is set by its [`ViolinPlot`] parent.
+    pub fn new(argument: f64, samples: impl Into<Vec<f64>>) -> Self {
+        let mut samples = samples.into();
+        samples.retain(|sample| sample.is_finite());
+        samples.sort_by(|a, b| a.total_cmp(b));
+        let spread = if samples.is_empty() {
+            BoxSpread::new(0.0, 0.0, 0.0, 0.0, 0.0)
+        } else {
+            BoxSpread::new(
+                samples[0],
+                quantile(&samples, 0.25),
+                quantile(&samples, 0.5),
+                quantile(&samples, 0.75),
+                samples[samples.len() - 1],
+            )
+        };
+        let mut violin = Self {
+            name: String::new(),
+            orientation: Orientation::default(),
+            argument,
+            bandwidth: silverman_bandwidth(&samples, &spread),
+            samples,
+            spread,
+            density: Vec::new(),
+            width: 0.8,
+            side: ViolinSide::default(),
+            show_box: true,
+            box_width: 0.05,
+            stroke: Stroke::new(1.0, Color32::TRANSPARENT),
+            fill: Color32::TRANSPARENT,
+        };
+        violin.update_density();
+        violin
+    }
+
+    /// Name of this violin.
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    pub fn name(mut self, name: impl ToString) -> Self {
+        self.name = name.to_string();
+        self
+    }
+
+    /// Set the bandwidth of the kernel. Larger values give a smoother violin.
+    #[inline]
+    pub fn bandwidth(mut self, bandwidth: f64) -> Self {
+        self.bandwidth = bandwidth;
+        self.update_density();
+        self
+    }
+
+    /// Set the width of the violin where its density is highest. Default: `0.8`.
+    #[inline]
+    pub fn width(mut self, width: f64) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Draw only one half of the violin. Default: [`ViolinSide::Both`].
+    #[inline]
+    pub fn side(mut self, side: ViolinSide) -> Self {
+        self.side = side;
+        self
+    }
+
+    /// Show a narrow box from the first to the third quartile with a dot at the median,
+    /// and a line from the smallest to the largest sample. Default: `true`.
+    #[inline]
+    pub fn show_box(mut self, show_box: bool) -> Self {
+        self.show_box = show_box;
+        self
+    }
+
+    /// Set the width of the embedded box. Default: `0.05`.
+    #[inline]
+    pub fn box_width(mut self, width: f64) -> Self {
+        self.box_width = width;
+        self
+    }
+
+    /// Add a custom stroke.
+    #[inline]
+    pub fn stroke(mut self, stroke: impl Into<Stroke>) -> Self {
+        self.stroke = stroke.into();
+        self
+    }
+
+    /// Add a custom fill color.
+    #[inline]
+    pub fn fill(mut self, color: impl Into<Color32>) -> Self {
+        self.fill = color.into();
+        self
+    }
+
+    /// Set orientation of the element as vertical. Argument axis is X.
+    #[inline]
+    pub fn vertical(mut self) -> Self {
+        self.orientation = Orientation::Vertical;
+        self
+    }
+
+    /// Set orientation of the element as horizontal. Argument axis is Y.
+    #[inline]
+    pub fn horizontal(mut self) -> Self {
+        self.orientation = Orientation::Horizontal;
+        self
+    }
+
+    /// The quartiles of the samples, with their smallest and largest value as whiskers.
+    pub fn spread(&self) -> &BoxSpread {
+        &self.spread
+    }
+
+    /// The estimated density at `value`.
+    pub fn density_at(&self, value: f64) -> f64 {
+        if self.samples.is_empty() || self.bandwidth <= 0.0 {
+            return 0.0;
+        }
+        let normalization =
+            1.0 / (self.samples.len() as f64 * self.bandwidth * std::f64::consts::TAU.sqrt());
+        normalization
+            * self
+                .samples
+                .iter()
+                .map(|sample| (-0.5 * ((value - sample) / self.bandwidth).powi(2)).exp())
+                .sum::<f64>()
+    }
+
+    fn update_density(&mut self) {
+        self.density.clear();
+        if self.samples.is_empty() {
+            return;
+        }
+        let start = self.spread.lower_whisker - DENSITY_CUT * self.bandwidth;
+        let end = self.spread.upper_whisker + DENSITY_CUT * self.bandwidth;
+        self.density = (0..NUM_DENSITY_POINTS)
+            .map(|i| {
+                let value = start + (end - start) * i as f64 / (NUM_DENSITY_POINTS - 1) as f64;
+                [value, self.density_at(value)]
+            })
+            .collect();
+    }
+
+    /// How far the violin extends from its argument to each side, at the peak of the density.
+    fn half_widths(&self) -> [f64; 2] {
+        let half_width = 0.5 * self.width;
+        match self.side {
+            ViolinSide::Both => [half_width, half_width],
+            ViolinSide::Low => [half_width, 0.0],
+            ViolinSide::High => [0.0, half_width],
+        }
+    }
+
+    pub(super) fn add_shapes(
+        &self,
+        transform: &PlotTransform,
+        highlighted: bool,
+        shapes: &mut Vec<Shape>,
+    ) {
+        let (stroke, fill) = if highlighted {
+            highlighted_color(self.stroke, self.fill)
+        } else {
+            (self.stroke, self.fill)
+        };
+
+        let max_density = self
+            .density
+            .iter()
+            .map(|[_, density]| *density)
+            .fold(0.0, f64::max);
+        if 0.0 < max_density {
+            let [low_width, high_width] = self.half_widths();
+            let edge = |half_width: f64, sign: f64| -> Vec<Pos2> {
+                self.density
+                    .iter()
+                    .map(|&[value, density]| {
+                        let argument = self.argument + sign * half_width * density / max_density;
+                        transform.position_from_point(&self.point_at(argument, value))
+                    })
+                    .collect()
+            };
+            let low_edge = edge(low_width, -1.0);
+            let high_edge = edge(high_width, 1.0);
+
+            let mut mesh = Mesh::default();
+            for (low, high) in low_edge.windows(2).zip(high_edge.windows(2)) {
+                let index = mesh.vertices.len() as u32;
+                for pos in [low[0], high[0], high[1], low[1]] {
+                    mesh.colored_vertex(pos, fill);
+                }
+                mesh.add_triangle(index, index + 1, index + 2);
+                mesh.add_triangle(index, index + 2, index + 3);
+            }
+            shapes.push(Shape::mesh(mesh));
+
+            let outline = low_edge
+                .into_iter()
+                .chain(high_edge.into_iter().rev())
+                .collect();
+            shapes.push(Shape::closed_line(outline, stroke));
+        }
+
+        if self.show_box {
+            let [low_width, high_width] = self.half_widths().map(|half_width| {
+                if half_width > 0.0 {
+                    0.5 * self.box_width
+                } else {
+                    0.0
+                }
+            });
+            let spread = &self.spread;
+            shapes.push(Shape::line_segment(
+                [
+                    transform
+                        .position_from_point(&self.point_at(self.argument, spread.lower_whisker)),
+                    transform
+                        .position_from_point(&self.point_at(self.argument, spread.upper_whisker)),
+                ],
+                stroke,
+            ));
+            let rect = transform.rect_from_values(
+                &self.point_at(self.argument - low_width, spread.quartile1),
+                &self.point_at(self.argument + high_width, spread.quartile3),
+            );
+            shapes.push(Shape::rect_filled(rect, 0.0, stroke.color));
+            let median =
+                transform.position_from_point(&self.point_at(self.argument, spread.median));
+            shapes.push(Shape::circle_filled(
+                median,
+                0.4 * rect.width().min(rect.height()).at_most(8.0),
+                Color32::WHITE,
+            ));
+        }
+    }
+
+    pub(super) fn add_rulers_and_text(
+        &self,
+        plot: &PlotConfig<'_>,
+        value: Option<f64>,
+        shapes: &mut Vec<Shape>,
+        cursors: &mut Vec<Cursor>,
+    ) {
+        let mut text = self.name.clone();
+        if !text.is_empty() {
+            text.push('\n');
+        }
+        text.push_str(&self.default_values_format(plot.transform));
+        if let Some(value) = value {
+            let decimals = self.decimals(plot.transform);
+            text.push_str(&format!(
+                "\nDensity at {value:.decimals$} = {:.3}",
+                self.density_at(value)
+            ));
+        }
+        add_rulers_and_text(self, plot, Some(text), shapes, cursors);
+    }
+
+    /// The number of decimals to show values with.
+    fn decimals(&self, transform: &PlotTransform) -> usize {
+        let scale = transform.dvalue_dpos_at(&self.point_at(self.argument, self.spread.median));
+        let scale = match self.orientation {
+            Orientation::Horizontal => scale[0],
+            Orientation::Vertical => scale[1],
+        };
+        ((-scale.abs().log10()).ceil().at_least(0.0) as usize)
+            .at_most(6)
+            .at_least(1)
+    }
+}
+
+/// Silverman's rule of thumb for the bandwidth of a gaussian kernel.
+fn silverman_bandwidth(samples: &[f64], spread: &BoxSpread) -> f64 {
+    let n = samples.len() as f64;
+    if n < 2.0 {
+        return 1.0;
+    }
+    let mean = samples.iter().sum::<f64>() / n;
+    let std_dev = (samples
+        .iter()
+        .map(|sample| (sample - mean).powi(2))
+        .sum::<f64>()
+        / (n - 1.0))
+        .sqrt();
+    let iqr = spread.quartile3 - spread.quartile1;
+    let scale = if iqr > 0.0 {
+        std_dev.min(iqr / 1.34)
+    } else {
+        std_dev
+    };
+    if scale > 0.0 {
+        0.9 * scale * n.powf(-0.2)
+    } else {
+        1.0
+    }
+}
+
+impl RectElement for ViolinElem {
+    fn name(&self) -> &str {
+        self.name.as_str()
+    }
+
+    fn bounds_min(&self) -> PlotPoint {
+        let [low_width, _] = self.half_widths();
+        let low_width = low_width.max(0.5 * self.box_width);
+        let value = self
+            .density
+            .first()
+            .map_or(self.spread.lower_whisker, |[value, _]| *value);
+        self.point_at(self.argument - low_width, value)
+    }
+
+    fn bounds_max(&self) -> PlotPoint {
+        let [_, high_width] = self.half_widths();
+        let high_width = high_width.max(0.5 * self.box_width);
+        let value = self
+            .density
+            .last()
+            .map_or(self.spread.upper_whisker, |[value, _]| *value);
+        self.point_at(self.argument + high_width, value)
+    }
+
+    fn values_with_ruler(&self) -> Vec<PlotPoint> {
+        [
+            self.spread.median,
+            self.spread.quartile1,
+            self.spread.quartile3,
+        ]
+        .map(|value| self.point_at(self.argument, value))
+        .to_vec()
+    }
+
+    fn orientation(&self) -> Orientation {
+        self.orientation
+    }
+
+    fn corner_value(&self) -> PlotPoint {
+        self.point_at(self.argument, self.spread.upper_whisker)
+    }
+
+    fn default_values_format(&self, transform: &PlotTransform) -> String {
+        format!(
+            "Quartile 3 = {q3:.decimals$}\
+             \nMedian = {med:.decimals$}\
+             \nQuartile 1 = {q1:.decimals$}",
+            q3 = self.spread.quartile3,
+            med = self.spread.median,
+            q1 = self.spread.quartile1,
+            decimals = self.decimals(transform)
+        )
+    }
+}
+
+/// A diagram containing a series of [`ViolinElem`] elements.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// use egui_plot::{Plot, ViolinElem, ViolinPlot, ViolinSide};
+///
+/// let before = vec![4.1, 5.0, 5.2, 5.9, 6.3, 7.5];
+/// let after = vec![5.5, 6.1, 6.8, 7.0, 7.2, 8.9];
+/// Plot::new("violins").show(ui, |plot_ui| {
+///     plot_ui.violin_plot(ViolinPlot::new(
+///         "Before",
+///         vec![ViolinElem::new(1.0, before).side(ViolinSide::Low)],
+///     ));
+///     plot_ui.violin_plot(ViolinPlot::new(
+///         "After",
+///         vec![ViolinElem::new(1.0, after).side(ViolinSide::High)],
+///     ));
+/// });
+/// # });
+/// ```
+pub struct ViolinPlot {
+    base: PlotItemBase,
+    pub(crate) violins: Vec<ViolinElem>,
+    pub(crate) default_color: Color32,
+}
+
+impl ViolinPlot {
+    /// Create a plot containing multiple `violins`. It defaults to vertically oriented elements.
+    pub fn new(name: impl Into<String>, violins: Vec<ViolinElem>) -> Self {
+        Self {
+            base: PlotItemBase::new(name.into()),
+            violins,
+            default_color: Color32::TRANSPARENT,
+        }
+    }
+
+    /// Set the default color. It is set on all elements that do not already have a specific color.
+    /// This is the color that shows up in the legend.
+    /// It can be overridden at the element level (see [`ViolinElem`]).
+    /// Default is `Color32::TRANSPARENT` which means a color will be auto-assigned.
+    #[inline]
+    pub fn color(mut self, color: impl Into<Color32>) -> Self {
+        let plot_color = color.into();
+        self.default_color = plot_color;
+        for violin in &mut self.violins {
+            if violin.fill == Color32::TRANSPARENT && violin.stroke.color == Color32::TRANSPARENT {
+                violin.fill = plot_color.linear_multiply(0.2);
+                violin.stroke.color = plot_color;
+            }
+        }
+        self
+    }
+
+    /// Set all elements to be in a vertical orientation.
+    /// Argument axis will be X and values will be on the Y axis.
+    #[inline]
+    pub fn vertical(mut self) -> Self {
+        for violin in &mut self.violins {
+            violin.orientation = Orientation::Vertical;
+        }
+        self
+    }
+
+    /// Set all elements to be in a horizontal orientation.
+    /// Argument axis will be Y and values will be on the X axis.
+    #[inline]
+    pub fn horizontal(mut self) -> Self {
+        for violin in &mut self.violins {
+            violin.orientation = Orientation::Horizontal;
+        }
+        self
+    }
+
+    builder_methods_for_base!();
+}
+
+impl PlotItem for ViolinPlot {
+    fn shapes(&self, _ui: &Ui, transform: &PlotTransform, shapes: &mut Vec<Shape>) {
+        for violin in &self.violins {
+            violin.add_shapes(transform, self.base.highlight, shapes);
+        }
+    }
+
+    fn initialize(&mut self, _x_range: RangeInclusive<f64>) {}
+
+    fn color(&self) -> Color32 {
+        self.default_color
+    }
+
+    fn geometry(&self) -> PlotGeometry<'_> {
+        PlotGeometry::Rects
+    }
+
+    fn bounds(&self) -> PlotBounds {
+        let mut bounds = PlotBounds::NOTHING;
+        for violin in &self.violins {
+            bounds.merge(&violin.bounds());
+        }
+        bounds
+    }
+
+    fn find_closest(&self, point: Pos2, transform: &PlotTransform) -> Option<ClosestElem> {
+        find_closest_rect(&self.violins, point, transform)
+    }
+
+    fn on_hover(
+        &self,
+        plot_area_response: &egui::Response,
+        elem: ClosestElem,
+        shapes: &mut Vec<Shape>,
+        cursors: &mut Vec<Cursor>,
+        plot: &PlotConfig<'_>,
+        _: &LabelFormatter<'_>,
+    ) {
+        let violin = &self.violins[elem.index];
+        let value = plot_area_response.hover_pos().map(|pos| {
+            let value = plot.transform.value_from_position(pos);
+            match violin.orientation {
+                Orientation::Horizontal => value.x,
+                Orientation::Vertical => value.y,
+            }
+        });
+
+        violin.add_shapes(plot.transform, true, shapes);
+        violin.add_rulers_and_text(plot, value, shapes, cursors);
+    }
+
+    fn base(&self) -> &PlotItemBase {
+        &self.base
+    }
+
+    fn base_mut(&mut self) -> &mut PlotItemBase {
+        &mut self.base
+    }
+}
+
+#[test]
+fn test_violin_density() {
+    let violin = ViolinElem::new(0.0, vec![1.0, 2.0, 3.0, 4.0, f64::NAN]).bandwidth(0.5);
+    assert_eq!(violin.spread(), &BoxSpread::new(1.0, 1.75, 2.5, 3.25, 4.0));
+
+    // The estimated density integrates to one:
+    let step = violin.density[1][0] - violin.density[0][0];
+    let area: f64 = violin
+        .density
+        .iter()
+        .map(|[_, density]| density * step)
+        .sum();
+    assert!((area - 1.0).abs() < 0.02, "{area}");
+    assert!(violin.density_at(2.5) < violin.density_at(2.0));
+}
@@ -9,13 +9,21 @@
 //!
 
 mod axis;
+mod categorical;
+mod colorbar;
+mod colormap;
+mod constraints;
 mod items;
 mod legend;
 mod memory;
+mod plot_grid;
 mod plot_ui;
+mod polar;
+mod scale;
+mod time_axis;
 mod transform;
 
-use std::{cmp::Ordering, ops::RangeInclusive, sync::Arc};
+use std::{cmp::Ordering, collections::BTreeMap, ops::RangeInclusive, sync::Arc};
 
 use ahash::HashMap;
 use egui::{
@@ -26,21 +34,32 @@ use egui::{
 use emath::Float as _;
 
 pub use crate::{
-    axis::{Axis, AxisHints, HPlacement, Placement, VPlacement},
+    axis::{Axis, AxisHints, HPlacement, Placement, TickMarks, TickPlacement, VPlacement},
+    categorical::CategoricalAxis,
+    colorbar::Colorbar,
+    colormap::Colormap,
     items::{
-        Arrows, Bar, BarChart, BoxElem, BoxPlot, BoxSpread, ClosestElem, HLine, Line, LineStyle,
-        MarkerShape, Orientation, PlotConfig, PlotGeometry, PlotImage, PlotItem, PlotItemBase,
-        PlotPoint, PlotPoints, Points, Polygon, Text, VLine,
+        Arrows, Bar, BarChart, BoxElem, BoxPlot, BoxSpread, Candle, CandleStyle, CandlestickChart,
+        ClosestElem, Contour, ErrorBars, FillBetween, HLine, Heatmap, Histogram, HistogramBins,
+        Line, LineStyle, MarkerShape, Orientation, PlotConfig, PlotGeometry, PlotImage, PlotItem,
+        PlotItemBase, PlotPoint, PlotPoints, PointErrors, Points, Polygon, StackOffset,
+        StackedAreas, StepMode, Text, VLine, ViolinElem, ViolinPlot, ViolinSide,
     },
     legend::{ColorConflictHandling, Corner, Legend},
     memory::PlotMemory,
+    plot_grid::{PlotGrid, PlotGridUi},
     plot_ui::PlotUi,
+    polar::{AngleUnit, Polar},
+    scale::{AxisScale, Scale},
+    time_axis::TimeAxis,
     transform::{PlotBounds, PlotTransform},
 };
 
 use axis::AxisWidget;
-use items::{horizontal_line, rulers_color, vertical_line};
+use constraints::BoundsConstraints;
+use items::{horizontal_line, points_bounds_in_range, rulers_color, vertical_line};
 use legend::LegendWidget;
+use memory::SecondaryAxisMemory;
 
 type LabelFormatterFn<'a> = dyn Fn(&str, &PlotPoint) -> String + 'a;
 pub type LabelFormatter<'a> = Option<Box<LabelFormatterFn<'a>>>;
@@ -155,6 +174,7 @@ pub struct Plot<'a> {
     id: Option<Id>,
 
     center_axis: Vec2b,
+    invert_axis: Vec2b,
     allow_zoom: Vec2b,
     allow_drag: Vec2b,
     allow_axis_zoom_drag: Vec2b,
@@ -183,6 +203,7 @@ pub struct Plot<'a> {
     x_axes: Vec<AxisHints<'a>>, // default x axes
     y_axes: Vec<AxisHints<'a>>, // default y axes
     legend_config: Option<Legend>,
+    colorbar: Option<Colorbar<'a>>,
     cursor_color: Option<Color32>,
     show_background: bool,
     show_axes: Vec2b,
@@ -191,6 +212,11 @@ pub struct Plot<'a> {
     grid_spacing: Rangef,
     grid_spacers: [GridSpacer<'a>; 2],
     clamp_grid: bool,
+    axis_scales: [AxisScale; 2],
+    polar: Option<Polar>,
+    constraints: BoundsConstraints,
+    follow_x: Option<f64>,
+    auto_fit_visible: Vec2b,
 
     sense: Sense,
 }
@@ -203,6 +229,7 @@ impl<'a> Plot<'a> {
             id: None,
 
             center_axis: false.into(),
+            invert_axis: false.into(),
             allow_zoom: true.into(),
             allow_drag: true.into(),
             allow_axis_zoom_drag: true.into(),
@@ -231,6 +258,7 @@ impl<'a> Plot<'a> {
             x_axes: vec![AxisHints::new(Axis::X)],
             y_axes: vec![AxisHints::new(Axis::Y)],
             legend_config: None,
+            colorbar: None,
             cursor_color: None,
             show_background: true,
             show_axes: true.into(),
@@ -239,6 +267,11 @@ impl<'a> Plot<'a> {
             grid_spacing: Rangef::new(8.0, 300.0),
             grid_spacers: [log_grid_spacer(10), log_grid_spacer(10)],
             clamp_grid: false,
+            axis_scales: Default::default(),
+            polar: None,
+            constraints: BoundsConstraints::default(),
+            follow_x: None,
+            auto_fit_visible: false.into(),
 
             sense: egui::Sense::click_and_drag(),
         }
@@ -326,6 +359,21 @@ impl<'a> Plot<'a> {
         self
     }
 
+    /// Let the X-axis grow to the left instead of to the right. Default: `false`.
+    #[inline]
+    pub fn invert_x(mut self, on: bool) -> Self {
+        self.invert_axis.x = on;
+        self
+    }
+
+    /// Let the Y-axis grow downwards instead of upwards, e.g. for depth or image pixel
+    /// coordinates. Default: `false`.
+    #[inline]
+    pub fn invert_y(mut self, on: bool) -> Self {
+        self.invert_axis.y = on;
+        self
+    }
+
     /// Whether to allow zooming in the plot. Default: `true`.
     ///
     /// Note: Allowing zoom in one axis but not the other may lead to unexpected results if used in combination with `data_aspect`.
@@ -381,6 +429,55 @@ impl<'a> Plot<'a> {
         self
     }
 
+    /// Never show x values outside of this range, no matter how the plot is panned or zoomed.
+    ///
+    /// This applies to dragging, scrolling, zooming, auto-bounds and [`PlotUi::set_plot_bounds`].
+    /// A reversed range is swapped, and an empty one (e.g. `3.0..=3.0`) is ignored.
+    #[inline]
+    pub fn x_limits(mut self, limits: RangeInclusive<f64>) -> Self {
+        self.constraints.limits[0] = constraints::limits(limits);
+        self
+    }
+
+    /// Never show y values outside of this range, see [`Self::x_limits`].
+    #[inline]
+    pub fn y_limits(mut self, limits: RangeInclusive<f64>) -> Self {
+        self.constraints.limits[1] = constraints::limits(limits);
+        self
+    }
+
+    /// The smallest and largest width of the visible x range, in plot units.
+    ///
+    /// Zooming stops when it reaches these, so a minimum keeps the bounds from becoming degenerate.
+    /// A reversed range is swapped, a minimum of zero or less is raised to the smallest positive
+    /// span, and a range without any positive span is ignored.
+    #[inline]
+    pub fn x_span_limits(mut self, span: RangeInclusive<f64>) -> Self {
+        self.constraints.span_limits[0] = constraints::span_limits(span);
+        self
+    }
+
+    /// The smallest and largest height of the visible y range, see [`Self::x_span_limits`].
+    #[inline]
+    pub fn y_span_limits(mut self, span: RangeInclusive<f64>) -> Self {
+        self.constraints.span_limits[1] = constraints::span_limits(span);
+        self
+    }
+
+    /// Don't allow panning past the data: when zoomed in, the view stays within the
+    /// bounds of the plot items (plus the [margin](Self::set_margin_fraction)),
+    /// and when zoomed out, all of them stay visible.
+    ///
+    /// Default: `false`.
+    #[inline]
+    pub fn keep_data_in_view<T>(mut self, on: T) -> Self
+    where
+        T: Into<Vec2b>,
+    {
+        self.constraints.keep_data_in_view = on.into();
+        self
+    }
+
     /// Whether to allow dragging in the plot to move the bounds. Default: `true`.
     #[inline]
     pub fn allow_drag<T>(mut self, on: T) -> Self
@@ -469,7 +566,8 @@ impl<'a> Plot<'a> {
     /// # ()
     /// ```
     ///
-    /// There are helpers for common cases, see [`log_grid_spacer`] and [`uniform_grid_spacer`].
+    /// There are helpers for common cases, see [`log_grid_spacer`], [`nice_grid_spacer`]
+    /// and [`uniform_grid_spacer`].
     #[inline]
     pub fn x_grid_spacer(mut self, spacer: impl Fn(GridInput) -> Vec<GridMark> + 'a) -> Self {
         self.grid_spacers[0] = Box::new(spacer);
@@ -505,6 +603,29 @@ impl<'a> Plot<'a> {
         self
     }
 
+    /// Set how values along the x axis are mapped to the screen, e.g. logarithmically.
+    ///
+    /// Data is still given in its original units. Grid lines, tick labels, zooming and
+    /// panning all follow the scale.
+    ///
+    /// Use [`AxisScale::custom`] to plug in your own [`Scale`].
+    ///
+    /// Default: [`AxisScale::Linear`].
+    #[inline]
+    pub fn x_axis_scale(mut self, scale: AxisScale) -> Self {
+        self.axis_scales[0] = scale;
+        self
+    }
+
+    /// Set how values along the y axis are mapped to the screen, e.g. logarithmically.
+    ///
+    /// See [`Self::x_axis_scale`].
+    #[inline]
+    pub fn y_axis_scale(mut self, scale: AxisScale) -> Self {
+        self.axis_scales[1] = scale;
+        self
+    }
+
     /// Set the sense for the plot rect.
     ///
     /// Default: `Sense::click_and_drag()`.
@@ -571,6 +692,37 @@ impl<'a> Plot<'a> {
         self
     }
 
+    /// Follow the newest data: show the last `window` x units up to the largest x of any item,
+    /// e.g. the last 30 seconds of a live signal.
+    ///
+    /// The y axis (if on auto-bounds) is fitted to the points in that window.
+    /// Following pauses when the user pans or zooms the x axis,
+    /// and resumes when they double-click the plot (see [`PlotMemory::follow_paused`]).
+    #[inline]
+    pub fn follow_x(mut self, window: f64) -> Self {
+        self.follow_x = Some(window);
+        self
+    }
+
+    /// Fit automatic bounds only to what is visible along the other axis.
+    ///
+    /// With `y` enabled, and the y axis on auto-bounds while the x axis isn't (e.g. after
+    /// zooming in on x), the y axis is fitted to the points in the visible x range,
+    /// so a spike outside of the view doesn't flatten everything on screen.
+    /// With `x` enabled, the same goes for the x axis and the visible y range.
+    ///
+    /// Items that aren't made of points (e.g. bars) are always fitted as a whole.
+    ///
+    /// Default: `false`.
+    #[inline]
+    pub fn auto_fit_visible<T>(mut self, on: T) -> Self
+    where
+        T: Into<Vec2b>,
+    {
+        self.auto_fit_visible = on.into();
+        self
+    }
+
     /// Expand bounds to fit all items across the x axis, including values given by `include_x`.
     #[deprecated = "Use `auto_bounds` instead"]
     #[inline]
@@ -594,6 +746,16 @@ impl<'a> Plot<'a> {
         self
     }
 
+    /// Show a colorbar next to the plot, outside of the axes and as high as the plot area.
+    ///
+    /// It goes on the right, unless its [`AxisHints`] are placed on the left.
+    /// Use [`Colorbar::for_item`] to show the colors of one of the plot items.
+    #[inline]
+    pub fn colorbar(mut self, colorbar: Colorbar<'a>) -> Self {
+        self.colorbar = Some(colorbar);
+        self
+    }
+
     /// Whether or not to show the background [`Rect`].
     ///
     /// Can be useful to disable if the plot is overlaid over existing content.
@@ -613,6 +775,22 @@ impl<'a> Plot<'a> {
         self
     }
 
+    /// Turn this into a polar plot, where the x coordinate of each point is an angle
+    /// and the y coordinate is the distance from the center.
+    ///
+    /// The grid becomes circles of constant radius and spokes of constant angle
+    /// (see [`Self::show_grid`]), the axes are hidden and the data aspect is fixed to 1.
+    /// The plot bounds refer to the cartesian plane the points are projected onto,
+    /// so boxed zoom selects a rectangle on that plane, and [`Self::invert_x`] and
+    /// [`Self::invert_y`] mirror the whole plot.
+    ///
+    /// Items that consist of points, like [`Line`], [`Points`] and [`Polygon`], are supported.
+    #[inline]
+    pub fn polar(mut self, polar: Polar) -> Self {
+        self.polar = Some(polar);
+        self
+    }
+
     /// Show a grid overlay on the plot.
     ///
     /// Default: `true`.
@@ -708,6 +886,60 @@ impl<'a> Plot<'a> {
         self
     }
 
+    /// Show unix timestamps (in seconds) along the x axis, with calendar-aware grid lines
+    /// and date/time labels.
+    ///
+    /// This sets the grid spacer and formatter of the main x axis, and the hover label
+    /// unless [`Self::label_formatter`] was called before.
+    /// See [`TimeAxis`] for using the parts separately, e.g. on the y axis.
+    pub fn x_axis_time(mut self, time_axis: TimeAxis) -> Self {
+        self.grid_spacers[0] = time_axis.grid_spacer();
+        if let Some(main) = self.x_axes.first_mut() {
+            main.formatter = Arc::new(move |mark, range| time_axis.format_mark(mark, range));
+        }
+        if self.label_formatter.is_none() {
+            self.label_formatter = Some(Box::new(time_axis.label_formatter()));
+        }
+        self
+    }
+
+    /// Show named categories along the x axis, with one grid line and label per category.
+    ///
+    /// Category `i` is at `x = i`, see [`CategoricalAxis`] and [`BarChart::from_categories`].
+    /// This sets the grid spacer, formatter, label spacing and label rotation of the main x axis,
+    /// and the hover label unless [`Self::label_formatter`] was called before.
+    pub fn x_axis_categories(mut self, categories: &CategoricalAxis) -> Self {
+        self.grid_spacers[0] = categories.grid_spacer();
+        if let Some(main) = self.x_axes.first_mut() {
+            let formatter_categories = categories.clone();
+            main.formatter =
+                Arc::new(move |mark, range| formatter_categories.format_mark(mark, range));
+            main.label_spacing = CategoricalAxis::label_spacing();
+            main.auto_tick_label_angle = Some(CategoricalAxis::AUTO_ROTATION);
+        }
+        if self.label_formatter.is_none() {
+            self.label_formatter = Some(Box::new(categories.label_formatter(Axis::X)));
+        }
+        self
+    }
+
+    /// Show named categories along the y axis, e.g. for horizontal bar charts.
+    ///
+    /// Category `i` is at `y = i`, see [`Self::x_axis_categories`].
+    pub fn y_axis_categories(mut self, categories: &CategoricalAxis) -> Self {
+        self.grid_spacers[1] = categories.grid_spacer();
+        if let Some(main) = self.y_axes.first_mut() {
+            let formatter_categories = categories.clone();
+            main.formatter =
+                Arc::new(move |mark, range| formatter_categories.format_mark(mark, range));
+            main.label_spacing = CategoricalAxis::label_spacing();
+        }
+        if self.label_formatter.is_none() {
+            self.label_formatter = Some(Box::new(categories.label_formatter(Axis::Y)));
+        }
+        self
+    }
+
     /// Specify custom formatter for ticks on the main Y-axis.
     ///
     /// Arguments of `fmt`:
@@ -788,6 +1020,7 @@ impl<'a> Plot<'a> {
             id_source,
             id,
             center_axis,
+            invert_axis,
             allow_zoom,
             allow_drag,
             allow_axis_zoom_drag,
@@ -810,6 +1043,7 @@ impl<'a> Plot<'a> {
             x_axes,
             y_axes,
             legend_config,
+            colorbar,
             cursor_color,
             reset,
             show_background,
@@ -821,9 +1055,21 @@ impl<'a> Plot<'a> {
 
             clamp_grid,
             grid_spacers,
+            axis_scales,
+            polar,
+            constraints,
+            follow_x,
+            auto_fit_visible,
             sense,
         } = self;
 
+        // A polar plot is laid out on a cartesian plane with equal units and no axes:
+        let (show_axes, data_aspect, axis_scales) = if polar.is_some() {
+            (Vec2b::FALSE, Some(1.0), Default::default())
+        } else {
+            (show_axes, data_aspect, axis_scales)
+        };
+
         // Disable interaction if ui is disabled.
         let allow_zoom = allow_zoom.and(ui.is_enabled());
         let allow_drag = allow_drag.and(ui.is_enabled());
@@ -866,11 +1112,29 @@ impl<'a> Plot<'a> {
         };
 
         let plot_id = id.unwrap_or_else(|| ui.make_persistent_id(id_source));
+        let previous_mem = PlotMemory::load(ui.ctx(), plot_id); // TODO(emilk): avoid loading plot memory twice
+
+        // Make room for the colorbar next to the axes:
+        let mut axes_rect = complete_rect;
+        let colorbar_x_range = colorbar.as_ref().map(|colorbar| {
+            let thickness = previous_mem
+                .as_ref()
+                .map_or(0.0, |mem| mem.colorbar_thickness);
+            let width = colorbar.width(thickness);
+            let gap = ui.spacing().item_spacing.x;
+            if colorbar.is_on_right() {
+                *axes_rect.right_mut() -= width + gap;
+                Rangef::new(axes_rect.right() + gap, complete_rect.right())
+            } else {
+                *axes_rect.left_mut() += width + gap;
+                Rangef::new(complete_rect.left(), axes_rect.left() - gap)
+            }
+        });
 
         let ([x_axis_widgets, y_axis_widgets], plot_rect) = axis_widgets(
-            PlotMemory::load(ui.ctx(), plot_id).as_ref(), // TODO(emilk): avoid loading plot memory twice
+            previous_mem.as_ref(),
             show_axes,
-            complete_rect,
+            axes_rect,
             [&x_axes, &y_axes],
         );
 
@@ -918,12 +1182,22 @@ impl<'a> Plot<'a> {
         }
         .unwrap_or_else(|| PlotMemory {
             auto_bounds: default_auto_bounds,
+            follow_paused: false,
             hovered_legend_item: None,
             hidden_items: Default::default(),
-            transform: PlotTransform::new(plot_rect, min_auto_bounds, center_axis),
+            transform: PlotTransform::new_with_scales(
+                plot_rect,
+                min_auto_bounds,
+                center_axis,
+                axis_scales,
+            )
+            .with_inverted(invert_axis)
+            .with_polar(polar),
             last_click_pos_for_zoom: None,
             x_axis_thickness: Default::default(),
             y_axis_thickness: Default::default(),
+            secondary_y_axes: Default::default(),
+            colorbar_thickness: 0.0,
         });
 
         let last_plot_transform = mem.transform;
@@ -1029,11 +1303,16 @@ impl<'a> Plot<'a> {
         };
 
         // Allow double-clicking to reset to the initial bounds.
-        if allow_double_click_reset && response.double_clicked() {
+        let double_clicked = allow_double_click_reset && response.double_clicked();
+        if double_clicked {
             mem.auto_bounds = true.into();
+            for axis in mem.secondary_y_axes.values_mut() {
+                axis.auto_bounds = true;
+            }
         }
 
         let any_dynamic_modifications = !bounds_modifications.is_empty();
+        let x_range_before_modifications = bounds.range_x();
         // Apply bounds modifications.
         for modification in bounds_modifications {
             match modification {
@@ -1054,13 +1333,28 @@ impl<'a> Plot<'a> {
                 }
                 BoundsModification::AutoBounds(new_auto_bounds) => {
                     mem.auto_bounds = new_auto_bounds;
+                    mem.follow_paused &= !new_auto_bounds.x;
                 }
                 BoundsModification::Zoom(zoom_factor, center) => {
-                    bounds.zoom(zoom_factor, center);
+                    let center = polar.map_or(center, |polar| polar.cartesian_from_polar(&center));
+                    // Zoom in the scaled space, so that e.g. log axes zoom evenly:
+                    let mut scaled_bounds = transform::bounds_to_scaled(&bounds, &axis_scales);
+                    let scaled_center = PlotPoint::new(
+                        axis_scales[0].forward(center.x),
+                        axis_scales[1].forward(center.y),
+                    );
+                    scaled_bounds.zoom(zoom_factor, scaled_center);
+                    bounds = transform::bounds_from_scaled(&scaled_bounds, &axis_scales);
                     mem.auto_bounds = false.into();
                 }
             }
         }
+        update_follow_paused(
+            &mut mem.follow_paused,
+            double_clicked,
+            &x_range_before_modifications,
+            &bounds.range_x(),
+        );
 
         // Reset bounds to initial bounds if they haven't been modified.
         if (!default_auto_bounds.x && !any_dynamic_modifications) || mem.auto_bounds.x {
@@ -1070,31 +1364,85 @@ impl<'a> Plot<'a> {
             bounds.set_y(&min_auto_bounds);
         }
 
-        let auto_x = mem.auto_bounds.x && (!min_auto_bounds.is_valid_x() || default_auto_bounds.x);
+        // Slide the x axis along with the newest data:
+        let follow_window = follow_x.filter(|_| !mem.follow_paused);
+        if let Some((min, max)) = follow_window.and_then(|window| follow_x_range(&items, window)) {
+            bounds.min[0] = min;
+            bounds.max[0] = max;
+        }
+
+        let auto_x = follow_window.is_none()
+            && mem.auto_bounds.x
+            && (!min_auto_bounds.is_valid_x() || default_auto_bounds.x);
         let auto_y = mem.auto_bounds.y && (!min_auto_bounds.is_valid_y() || default_auto_bounds.y);
 
         // Set bounds automatically based on content.
         if auto_x || auto_y {
+            // When only one axis is automatic, it can be fitted to what is visible along the other:
+            let fit_x_to_y_range = auto_fit_visible.x && !auto_y && polar.is_none();
+            let fit_y_to_x_range =
+                (auto_fit_visible.y || follow_window.is_some()) && !auto_x && polar.is_none();
+            let (x_range, y_range) = (bounds.range_x(), bounds.range_y());
+
             for item in &items {
-                let item_bounds = item.bounds();
+                let item_bounds = if polar.is_some() {
+                    polar_item_bounds(&**item)
+                } else {
+                    item_bounds_on_scales(&**item, &axis_scales)
+                };
+                let is_main_y = item.secondary_y_axis().is_none();
                 if auto_x {
-                    bounds.merge_x(&item_bounds);
+                    if fit_x_to_y_range && is_main_y {
+                        let visible =
+                            item_bounds_in_range(&**item, Axis::Y, &y_range, &axis_scales);
+                        bounds.merge_x(&visible);
+                    } else {
+                        bounds.merge_x(&item_bounds);
+                    }
                 }
-                if auto_y {
-                    bounds.merge_y(&item_bounds);
+                if auto_y && is_main_y {
+                    if fit_y_to_x_range {
+                        let visible =
+                            item_bounds_in_range(&**item, Axis::X, &x_range, &axis_scales);
+                        bounds.merge_y(&visible);
+                    } else {
+                        bounds.merge_y(&item_bounds);
+                    }
                 }
             }
 
+            // The margin is added in the scaled space, so it looks the same on both ends of the axis:
+            let mut scaled_bounds = transform::bounds_to_scaled(&bounds, &axis_scales);
             if auto_x {
-                bounds.add_relative_margin_x(margin_fraction);
+                scaled_bounds.add_relative_margin_x(margin_fraction);
             }
 
             if auto_y {
-                bounds.add_relative_margin_y(margin_fraction);
+                scaled_bounds.add_relative_margin_y(margin_fraction);
+            }
+            let margin_bounds = transform::bounds_from_scaled(&scaled_bounds, &axis_scales);
+            if auto_x {
+                bounds.set_x(&margin_bounds);
+            }
+            if auto_y {
+                bounds.set_y(&margin_bounds);
             }
         }
 
-        mem.transform = PlotTransform::new(plot_rect, bounds, center_axis);
+        // Pan and zoom constraints, which are enforced after every change below:
+        let data_bounds = if constraints.keep_data_in_view.any() {
+            data_bounds(&items, polar.as_ref(), &axis_scales, margin_fraction)
+        } else {
+            PlotBounds::NOTHING
+        };
+        let constrain = |transform: &mut PlotTransform, before: &PlotBounds| {
+            if constraints.is_active() {
+                transform.set_bounds(constraints.apply(before, transform.bounds(), &data_bounds));
+            }
+        };
+        mem.transform = PlotTransform::new_with_scales(plot_rect, bounds, center_axis, axis_scales)
+            .with_inverted(invert_axis)
+            .with_polar(polar);
 
         // Enforce aspect ratio
         if let Some(data_aspect) = data_aspect {
@@ -1112,6 +1460,25 @@ impl<'a> Plot<'a> {
             }
         }
 
+        // The constraints come last, so the aspect ratio can't push the bounds past them:
+        constrain(&mut mem.transform, last_plot_transform.bounds());
+
+        // The secondary y axes are fitted like the main one, on the same y scale:
+        let fit_to_x_range = ((auto_fit_visible.y || follow_window.is_some()) && polar.is_none())
+            .then(|| mem.transform.bounds().range_x());
+        fit_secondary_y_axes(
+            &mut mem.secondary_y_axes,
+            &items,
+            &y_axes,
+            fit_to_x_range.as_ref(),
+            &axis_scales,
+            margin_fraction,
+        );
+
+        // The secondary y axes follow what the user does to the main y axis below:
+        let scaled_y_range_before_interaction = mem.transform.scaled_bounds().range_y();
+        let x_range_before_interaction = mem.transform.bounds().range_x();
+
         // Dragging
         if allow_drag.any() && response.dragged_by(PointerButton::Primary) {
             response = response.on_hover_cursor(CursorIcon::Grabbing);
@@ -1122,22 +1489,28 @@ impl<'a> Plot<'a> {
             if !allow_drag.y {
                 delta.y = 0.0;
             }
+            let before = *mem.transform.bounds();
             mem.transform
                 .translate_bounds((delta.x as f64, delta.y as f64));
+            constrain(&mut mem.transform, &before);
             mem.auto_bounds = mem.auto_bounds.and(!allow_drag);
         }
 
         // Drag axes to zoom:
         for d in 0..2 {
             if allow_axis_zoom_drag[d] {
-                if let Some(axis_response) = (if d == 0 {
+                if let Some((_, axis_response)) = (if d == 0 {
                     &x_axis_responses
                 } else {
                     &y_axis_responses
                 })
                 .iter()
-                .find(|r| r.dragged_by(PointerButton::Primary))
-                {
+                .enumerate()
+                .find(|(_, r)| r.dragged_by(PointerButton::Primary))
+                .filter(|(index, _)| {
+                    // Secondary y axes are dragged on their own, see below.
+                    d == 0 || y_axis_widgets[*index].hints.secondary_y_axis.is_none()
+                }) {
                     if let Some(hover_pos) = axis_response.hover_pos() {
                         let delta = axis_response.drag_delta();
 
@@ -1155,7 +1528,9 @@ impl<'a> Plot<'a> {
                         if zoom != Vec2::splat(1.0) {
                             let mut zoom_center = plot_rect.center();
                             zoom_center[d] = hover_pos[d];
+                            let before = *mem.transform.bounds();
                             mem.transform.zoom(zoom, zoom_center);
+                            constrain(&mut mem.transform, &before);
                             mem.auto_bounds = false.into();
                         }
                     }
@@ -1195,20 +1570,13 @@ impl<'a> Plot<'a> {
                 }
                 // when the click is release perform the zoom
                 if response.drag_stopped() {
-                    let box_start_pos = mem.transform.value_from_position(box_start_pos);
-                    let box_end_pos = mem.transform.value_from_position(box_end_pos);
-                    let new_bounds = PlotBounds {
-                        min: [
-                            box_start_pos.x.min(box_end_pos.x),
-                            box_start_pos.y.min(box_end_pos.y),
-                        ],
-                        max: [
-                            box_start_pos.x.max(box_end_pos.x),
-                            box_start_pos.y.max(box_end_pos.y),
-                        ],
-                    };
+                    let new_bounds = mem
+                        .transform
+                        .plane_bounds_from_positions(box_start_pos, box_end_pos);
                     if new_bounds.is_valid() {
+                        let before = *mem.transform.bounds();
                         mem.transform.set_bounds(new_bounds);
+                        constrain(&mut mem.transform, &before);
                         mem.auto_bounds = false.into();
                     }
                     // reset the boxed zoom state
@@ -1237,7 +1605,9 @@ impl<'a> Plot<'a> {
                     zoom_factor.y = 1.0;
                 }
                 if zoom_factor != Vec2::splat(1.0) {
+                    let before = *mem.transform.bounds();
                     mem.transform.zoom(zoom_factor, hover_pos);
+                    constrain(&mut mem.transform, &before);
                     mem.auto_bounds = mem.auto_bounds.and(!allow_zoom);
                 }
             }
@@ -1250,47 +1620,125 @@ impl<'a> Plot<'a> {
                     scroll_delta.y = 0.0;
                 }
                 if scroll_delta != Vec2::ZERO {
+                    let before = *mem.transform.bounds();
                     mem.transform
                         .translate_bounds((-scroll_delta.x as f64, -scroll_delta.y as f64));
+                    constrain(&mut mem.transform, &before);
                     mem.auto_bounds = false.into();
                 }
             }
         }
 
+        // Dragging, zooming and scrolling over a secondary y axis only changes that axis:
+        for (widget, axis_response) in y_axis_widgets.iter().zip(&y_axis_responses) {
+            let Some(name) = widget.hints.secondary_y_axis.as_deref() else {
+                continue;
+            };
+            let (Some(axis), Some(hover_pos)) = (
+                mem.secondary_y_axes.get_mut(name),
+                axis_response.hover_pos(),
+            ) else {
+                continue;
+            };
+            let mut zoom_factor = if allow_zoom.y {
+                ui.input(|i| i.zoom_delta_2d().y)
+            } else {
+                1.0
+            };
+            if allow_axis_zoom_drag.y && axis_response.dragged_by(PointerButton::Primary) {
+                zoom_factor *= 1.0 + (0.02 * axis_response.drag_delta().y).clamp(-1.0, 1.0);
+            }
+            let scroll_delta = if allow_scroll.y {
+                ui.input(|i| i.smooth_scroll_delta.y)
+            } else {
+                0.0
+            };
+            if zoom_factor != 1.0 || scroll_delta != 0.0 {
+                change_secondary_y_axis(axis, &mem.transform, |transform| {
+                    transform.zoom(Vec2::new(1.0, zoom_factor), hover_pos);
+                    transform.translate_bounds((0.0, -scroll_delta as f64));
+                });
+            }
+        }
+
+        update_follow_paused(
+            &mut mem.follow_paused,
+            false,
+            &x_range_before_interaction,
+            &mem.transform.bounds().range_x(),
+        );
+
+        follow_main_y_axis(
+            &mut mem.secondary_y_axes,
+            &scaled_y_range_before_interaction,
+            &mem.transform.scaled_bounds().range_y(),
+            &axis_scales[1],
+        );
+
         // --- transform initialized
 
+        let mut secondary_transforms = BTreeMap::new();
+        for (name, axis) in &mut mem.secondary_y_axes {
+            let transform = mem.transform.with_y_range(&(axis.min..=axis.max));
+            // Remember the sanitized bounds:
+            axis.min = transform.bounds().min[1];
+            axis.max = transform.bounds().max[1];
+            secondary_transforms.insert(name.clone(), transform);
+        }
+
         // Add legend widgets to plot
         let bounds = mem.transform.bounds();
         let x_axis_range = bounds.range_x();
-        let x_steps = Arc::new({
-            let input = GridInput {
-                bounds: (bounds.min[0], bounds.max[0]),
-                base_step_size: mem.transform.dvalue_dpos()[0].abs() * grid_spacing.min as f64,
-            };
-            (grid_spacers[0])(input)
-        });
+        let x_steps = Arc::new(grid_marks(
+            &mem.transform,
+            Axis::X,
+            &grid_spacers[0],
+            grid_spacing.min,
+        ));
         let y_axis_range = bounds.range_y();
-        let y_steps = Arc::new({
-            let input = GridInput {
-                bounds: (bounds.min[1], bounds.max[1]),
-                base_step_size: mem.transform.dvalue_dpos()[1].abs() * grid_spacing.min as f64,
-            };
-            (grid_spacers[1])(input)
-        });
+        let y_steps = Arc::new(grid_marks(
+            &mem.transform,
+            Axis::Y,
+            &grid_spacers[1],
+            grid_spacing.min,
+        ));
         for (i, mut widget) in x_axis_widgets.into_iter().enumerate() {
             widget.range = x_axis_range.clone();
             widget.transform = Some(mem.transform);
             widget.steps = x_steps.clone();
+            convert_axis_widget(&mut widget, Axis::X, &grid_spacers[0], grid_spacing.min);
             let (_response, thickness) = widget.ui(ui, Axis::X);
             mem.x_axis_thickness.insert(i, thickness);
         }
         for (i, mut widget) in y_axis_widgets.into_iter().enumerate() {
-            widget.range = y_axis_range.clone();
-            widget.transform = Some(mem.transform);
-            widget.steps = y_steps.clone();
+            let secondary_transform = widget
+                .hints
+                .secondary_y_axis
+                .as_ref()
+                .and_then(|name| secondary_transforms.get(name));
+            if let Some(transform) = secondary_transform {
+                widget.range = transform.bounds().range_y();
+                widget.transform = Some(*transform);
+                widget.steps = Arc::new(grid_marks(
+                    transform,
+                    Axis::Y,
+                    &grid_spacers[1],
+                    grid_spacing.min,
+                ));
+            } else {
+                widget.range = y_axis_range.clone();
+                widget.transform = Some(mem.transform);
+                widget.steps = y_steps.clone();
+            }
+            convert_axis_widget(&mut widget, Axis::Y, &grid_spacers[1], grid_spacing.min);
             let (_response, thickness) = widget.ui(ui, Axis::Y);
             mem.y_axis_thickness.insert(i, thickness);
         }
+        if let (Some(mut colorbar), Some(x_range)) = (colorbar, colorbar_x_range) {
+            colorbar.follow_item(&items, mem.transform.bounds());
+            let rect = Rect::from_x_y_ranges(x_range, plot_rect.y_range());
+            mem.colorbar_thickness = colorbar.paint(ui, rect);
+        }
 
         // Initialize values from functions.
         for item in &mut items {
@@ -1307,6 +1755,7 @@ impl<'a> Plot<'a> {
             show_grid,
             grid_spacing,
             transform: mem.transform,
+            secondary_transforms,
             draw_cursor_x: linked_cursors.as_ref().is_some_and(|group| group.1.x),
             draw_cursor_y: linked_cursors.as_ref().is_some_and(|group| group.1.y),
             draw_cursors,
@@ -1530,6 +1979,529 @@ pub struct GridInput {
     pub base_step_size: f64,
 }
 
+/// All grid marks along the given axis.
+///
+/// Non-linear axis scales bring their own grid marks; otherwise the grid spacer is used.
+fn grid_marks(
+    transform: &PlotTransform,
+    axis: Axis,
+    grid_spacer: &GridSpacer<'_>,
+    min_spacing: f32,
+) -> Vec<GridMark> {
+    let iaxis = usize::from(axis);
+    let bounds = transform.bounds();
+    let input = GridInput {
+        bounds: (bounds.min[iaxis], bounds.max[iaxis]),
+        base_step_size: transform.dvalue_dpos()[iaxis].abs() * min_spacing as f64,
+    };
+    let scale = &transform.scales()[iaxis];
+    if let Some(marks) = scale.grid_marks(&input) {
+        return marks;
+    }
+    if scale.is_linear() {
+        return grid_spacer(input);
+    }
+
+    piecewise_grid_marks(transform, axis, |value| value, grid_spacer, min_spacing)
+}
+
+/// Give an axis with a unit conversion its own range and grid marks, in the converted unit.
+fn convert_axis_widget(
+    widget: &mut AxisWidget<'_>,
+    axis: Axis,
+    grid_spacer: &GridSpacer<'_>,
+    min_spacing: f32,
+) {
+    let (Some(conversion), Some(transform)) = (&widget.hints.conversion, &widget.transform) else {
+        return;
+    };
+    let start = (conversion.forward)(*widget.range.start());
+    let end = (conversion.forward)(*widget.range.end());
+    let steps = piecewise_grid_marks(
+        transform,
+        axis,
+        |value| (conversion.forward)(value),
+        grid_spacer,
+        min_spacing,
+    );
+    widget.range = start.min(end)..=start.max(end);
+    widget.steps = Arc::new(steps);
+}
+
+/// Grid marks for the values along an axis, mapped through the monotonic function `convert`.
+///
+/// The grid spacer is applied piece by piece, so that each part of the axis gets a step size
+/// that suits how much it is stretched there, e.g. by a non-linear scale.
+fn piecewise_grid_marks(
+    transform: &PlotTransform,
+    axis: Axis,
+    convert: impl Fn(f64) -> f64,
+    grid_spacer: &GridSpacer<'_>,
+    min_spacing: f32,
+) -> Vec<GridMark> {
+    const PIECES: usize = 8;
+    let iaxis = usize::from(axis);
+    let scale = &transform.scales()[iaxis];
+    let scaled_bounds = transform.scaled_bounds();
+    let scaled_range = scaled_bounds.min[iaxis]..=scaled_bounds.max[iaxis];
+    let piece_length_in_points = transform.frame().size()[iaxis] as f64 / PIECES as f64;
+    let value_at = |piece: usize| {
+        let scaled = emath::lerp(scaled_range.clone(), piece as f64 / PIECES as f64);
+        convert(scale.inverse(scaled))
+    };
+
+    let mut marks = Vec::new();
+    for piece in 0..PIECES {
+        let (start, end) = (value_at(piece), value_at(piece + 1));
+        let (min, max) = (start.min(end), start.max(end));
+        let base_step_size = (max - min) / piece_length_in_points * min_spacing as f64;
+        if !(min < max && base_step_size.is_finite() && 0.0 < base_step_size) {
+            continue;
+        }
+        let piece_marks = grid_spacer(GridInput {
+            bounds: (min, max),
+            base_step_size,
+        });
+        marks.extend(
+            piece_marks
+                .into_iter()
+                .filter(|mark| min <= mark.value && mark.value < max),
+        );
+    }
+    scale::sort_and_dedup_marks(&mut marks);
+    marks
+}
+
+#[test]
+fn test_convert_axis_widget() {
+    let frame = Rect::from_min_size(Pos2::ZERO, Vec2::new(300.0, 100.0));
+    let celsius = PlotBounds::from_min_max([0.0, 0.0], [100.0, 1.0]);
+    let transform = PlotTransform::new(frame, celsius, false);
+    let hints = AxisHints::new_x().conversion(
+        |celsius| celsius * 1.8 + 32.0,
+        |fahrenheit| (fahrenheit - 32.0) / 1.8,
+    );
+    let mut widget = AxisWidget::new(hints, Rect::NOTHING);
+    widget.range = 0.0..=100.0;
+    widget.transform = Some(transform);
+    convert_axis_widget(&mut widget, Axis::X, &log_grid_spacer(10), 10.0);
+
+    // The marks are round numbers in Fahrenheit, not converted round Celsius values:
+    assert_eq!(widget.range, 32.0..=212.0);
+    let values: Vec<f64> = widget.steps.iter().map(|mark| mark.value).collect();
+    for fahrenheit in [50.0, 100.0, 150.0, 200.0] {
+        assert!(values.contains(&fahrenheit), "{values:?}");
+    }
+    for mark in widget.steps.iter() {
+        let steps = mark.value / mark.step_size;
+        assert!((steps - steps.round()).abs() < 1e-9, "{mark:?}");
+        assert!(widget.range.contains(&mark.value), "{mark:?}");
+    }
+
+    // ... and they land where the main axis shows the same temperature:
+    for (fahrenheit, x) in [(50.0, 30.0), (212.0, 300.0)] {
+        let position = transform.position_from_point_x(widget.plot_value(fahrenheit));
+        assert!((position - x).abs() < 1e-3, "{fahrenheit}: {position}");
+    }
+}
+
+/// Forget secondary y axes that are no longer used, and fit those with auto-bounds to their items.
+///
+/// With `fit_to_x_range`, only the parts of the items within that x range are fitted.
+fn fit_secondary_y_axes(
+    secondary_y_axes: &mut BTreeMap<String, SecondaryAxisMemory>,
+    items: &[Box<dyn PlotItem + '_>],
+    y_axes: &[AxisHints<'_>],
+    fit_to_x_range: Option<&RangeInclusive<f64>>,
+    axis_scales: &[AxisScale; 2],
+    margin_fraction: Vec2,
+) {
+    let mut names: Vec<&str> = y_axes
+        .iter()
+        .filter_map(|hints| hints.secondary_y_axis.as_deref())
+        .chain(items.iter().filter_map(|item| item.secondary_y_axis()))
+        .collect();
+    names.sort_unstable();
+    names.dedup();
+    secondary_y_axes.retain(|name, _| names.contains(&name.as_str()));
+
+    for name in names {
+        let axis = secondary_y_axes.entry(name.to_owned()).or_default();
+        if !axis.auto_bounds {
+            continue;
+        }
+        let mut bounds = PlotBounds::NOTHING;
+        for item in items {
+            if item.secondary_y_axis() == Some(name) {
+                let item_bounds = match fit_to_x_range {
+                    Some(x_range) => item_bounds_in_range(&**item, Axis::X, x_range, axis_scales),
+                    None => item_bounds_on_scales(&**item, axis_scales),
+                };
+                bounds.merge_y(&item_bounds);
+            }
+        }
+        if bounds.is_valid_y() {
+            // The margin is added in the scaled space, like for the main y axis:
+            let mut scaled_bounds = transform::bounds_to_scaled(&bounds, axis_scales);
+            scaled_bounds.add_relative_margin_y(margin_fraction);
+            bounds = transform::bounds_from_scaled(&scaled_bounds, axis_scales);
+        }
+        axis.min = bounds.min[1];
+        axis.max = bounds.max[1];
+    }
+}
+
+/// Zoom or move a single secondary y axis, e.g. when its axis widget is dragged,
+/// by applying `change` to the transform it is drawn with.
+fn change_secondary_y_axis(
+    axis: &mut SecondaryAxisMemory,
+    main_transform: &PlotTransform,
+    change: impl FnOnce(&mut PlotTransform),
+) {
+    let mut transform = main_transform.with_y_range(&(axis.min..=axis.max));
+    change(&mut transform);
+    axis.min = transform.bounds().min[1];
+    axis.max = transform.bounds().max[1];
+    axis.auto_bounds = false;
+}
+
+/// The x range that [`Plot::follow_x`] shows: the last `window` x units up to the largest x
+/// of any item, or `None` if there is nothing to follow.
+fn follow_x_range(items: &[Box<dyn PlotItem + '_>], window: f64) -> Option<(f64, f64)> {
+    let newest = items
+        .iter()
+        .map(|item| item.bounds().max[0])
+        .filter(|x| x.is_finite())
+        .max_by_key(|x| x.ord())?;
+    Some((newest - window, newest))
+}
+
+/// Pause [`Plot::follow_x`] if the x range changed from `before` to `after`, i.e. the x axis
+/// was moved away from the newest data, and resume it on a double-click.
+fn update_follow_paused(
+    paused: &mut bool,
+    double_clicked: bool,
+    before: &RangeInclusive<f64>,
+    after: &RangeInclusive<f64>,
+) {
+    if double_clicked {
+        *paused = false;
+    } else {
+        *paused |= before != after;
+    }
+}
+
+#[test]
+fn test_follow_x() {
+    let signal = |newest: f64| -> Box<dyn PlotItem> {
+        Box::new(Line::new("signal", vec![[0.0, 1.0], [newest, 2.0]]))
+    };
+    let mut items = vec![signal(10.0)];
+    assert_eq!(follow_x_range(&items, 5.0), Some((5.0, 10.0)));
+
+    // The window slides along when newer data comes in, on any item:
+    items.push(signal(12.0));
+    assert_eq!(follow_x_range(&items, 5.0), Some((7.0, 12.0)));
+    assert_eq!(follow_x_range(&[], 5.0), None);
+
+    // Panning or zooming along x pauses, moving only the y axis doesn't:
+    let window = 7.0..=12.0;
+    let paused_after = |paused: bool, double_clicked: bool, after: RangeInclusive<f64>| {
+        let mut paused = paused;
+        update_follow_paused(&mut paused, double_clicked, &window, &after);
+        paused
+    };
+    assert!(paused_after(false, false, 6.0..=11.0));
+    assert!(paused_after(false, false, 6.0..=13.0));
+    assert!(!paused_after(false, false, window.clone()));
+    assert!(paused_after(true, false, window.clone()));
+
+    // A double-click resumes:
+    assert!(!paused_after(true, true, 6.0..=11.0));
+}
+
+/// Move and zoom the secondary y axes the same way the main y axis was moved and zoomed,
+/// so the items on them move along with everything else.
+///
+/// `before` and `after` are the main y range in the scaled space of `y_scale`.
+fn follow_main_y_axis(
+    secondary_y_axes: &mut BTreeMap<String, SecondaryAxisMemory>,
+    before: &RangeInclusive<f64>,
+    after: &RangeInclusive<f64>,
+    y_scale: &AxisScale,
+) {
+    let height = before.end() - before.start();
+    if !(0.0 < height && height.is_finite()) {
+        return;
+    }
+    let start = (after.start() - before.start()) / height;
+    let end = (after.end() - before.start()) / height;
+    if start.abs() < 1e-9 && (end - 1.0).abs() < 1e-9 {
+        return; // The main y axis didn't change (beyond rounding errors).
+    }
+    for axis in secondary_y_axes.values_mut() {
+        let (min, max) = (y_scale.forward(axis.min), y_scale.forward(axis.max));
+        let axis_height = max - min;
+        if axis_height.is_finite() {
+            axis.min = y_scale.inverse(min + start * axis_height);
+            axis.max = y_scale.inverse(min + end * axis_height);
+            axis.auto_bounds = false;
+        }
+    }
+}
+
+#[test]
+fn test_follow_main_y_axis() {
+    let mut secondary_y_axes = BTreeMap::new();
+    secondary_y_axes.insert(
+        "pressure".to_owned(),
+        SecondaryAxisMemory {
+            min: 1000.0,
+            max: 1100.0,
+            auto_bounds: true,
+        },
+    );
+
+    // Zoomed out by a factor of two around the bottom:
+    follow_main_y_axis(
+        &mut secondary_y_axes,
+        &(0.0..=10.0),
+        &(0.0..=20.0),
+        &AxisScale::Linear,
+    );
+    let axis = secondary_y_axes["pressure"];
+    assert_eq!((axis.min, axis.max), (1000.0, 1200.0));
+    assert!(!axis.auto_bounds);
+
+    // On a log axis, the same zoom happens in decades:
+    secondary_y_axes.get_mut("pressure").unwrap().min = 10.0;
+    secondary_y_axes.get_mut("pressure").unwrap().max = 100.0;
+    follow_main_y_axis(
+        &mut secondary_y_axes,
+        &(0.0..=1.0),
+        &(0.0..=2.0),
+        &AxisScale::Log10,
+    );
+    let axis = secondary_y_axes["pressure"];
+    assert!((axis.max - 1000.0).abs() < 1e-9);
+}
+
+/// The bounds of all items, with the auto-bounds margin, for [`Plot::keep_data_in_view`].
+///
+/// Items on secondary y axes only count along x.
+fn data_bounds(
+    items: &[Box<dyn PlotItem + '_>],
+    polar: Option<&Polar>,
+    axis_scales: &[AxisScale; 2],
+    margin_fraction: Vec2,
+) -> PlotBounds {
+    let mut bounds = PlotBounds::NOTHING;
+    for item in items {
+        let item_bounds = if polar.is_some() {
+            polar_item_bounds(&**item)
+        } else {
+            item_bounds_on_scales(&**item, axis_scales)
+        };
+        bounds.merge_x(&item_bounds);
+        if item.secondary_y_axis().is_none() {
+            bounds.merge_y(&item_bounds);
+        }
+    }
+    if !bounds.is_valid() {
+        return bounds;
+    }
+    let mut scaled_bounds = transform::bounds_to_scaled(&bounds, axis_scales);
+    scaled_bounds.add_relative_margin_x(margin_fraction);
+    scaled_bounds.add_relative_margin_y(margin_fraction);
+    transform::bounds_from_scaled(&scaled_bounds, axis_scales)
+}
+
+/// The bounds of an item in a polar plot: a square around the largest radius.
+fn polar_item_bounds(item: &dyn PlotItem) -> PlotBounds {
+    let bounds = item.bounds();
+    if !bounds.is_valid_y() {
+        return PlotBounds::NOTHING;
+    }
+    PlotBounds::new_symmetrical(bounds.min[1].abs().max(bounds.max[1].abs()))
+}
+
+/// The bounds of an item including its error bars, ignoring values that can't be shown
+/// on the given axis scales (e.g. zero or negative values on a log axis).
+fn item_bounds_on_scales(item: &dyn PlotItem, scales: &[AxisScale; 2]) -> PlotBounds {
+    let mut bounds = item.bounds();
+    for (axis, scale) in scales.iter().enumerate() {
+        if scale.is_linear() || scale.is_valid(bounds.min[axis]) {
+            continue;
+        }
+        let smallest_valid = match item.geometry() {
+            PlotGeometry::Points(points) => points
+                .iter()
+                .enumerate()
+                .flat_map(|(index, point)| point_extent(item, index, point, axis))
+                .filter(|value| scale.is_valid(*value))
+                .min_by_key(|value| value.ord()),
+            PlotGeometry::None | PlotGeometry::Rects => None,
+        };
+        match smallest_valid {
+            Some(value) => bounds.min[axis] = value,
+            None if scale.is_valid(bounds.max[axis]) => bounds.min[axis] = bounds.max[axis],
+            None => {
+                bounds.min[axis] = f64::INFINITY;
+                bounds.max[axis] = -f64::INFINITY;
+            }
+        }
+    }
+    bounds
+}
+
+/// The bounds of the points of an item whose values along `axis` are in `range`,
+/// e.g. to fit the y axis to the points in the visible x range.
+///
+/// Items that aren't made of points count as a whole.
+fn item_bounds_in_range(
+    item: &dyn PlotItem,
+    axis: Axis,
+    range: &RangeInclusive<f64>,
+    scales: &[AxisScale; 2],
+) -> PlotBounds {
+    let points = match item.geometry() {
+        PlotGeometry::Points(points) if !points.is_empty() => points,
+        PlotGeometry::Points(_) | PlotGeometry::None | PlotGeometry::Rects => {
+            return item_bounds_on_scales(item, scales);
+        }
+    };
+    let sorted = axis == Axis::X && item.sorted_by_x();
+    points_bounds_in_range(points, axis, range, sorted, |bounds, index, point| {
+        if !(scales[0].is_valid(point.x) && scales[1].is_valid(point.y)) {
+            return;
+        }
+        for (axis, scale) in scales.iter().enumerate() {
+            for value in point_extent(item, index, point, axis) {
+                if !scale.is_valid(value) {
+                    continue;
+                }
+                if axis == 0 {
+                    bounds.extend_with_x(value);
+                } else {
+                    bounds.extend_with_y(value);
+                }
+            }
+        }
+    })
+}
+
+/// The value of the point at `index` of an item along `axis`, and the two ends of its
+/// error bar along `axis` (which are the value itself if it has none).
+fn point_extent(item: &dyn PlotItem, index: usize, point: &PlotPoint, axis: usize) -> [f64; 3] {
+    let value = if axis == 0 { point.x } else { point.y };
+    let [lower, upper] = item.point_errors(index)[axis].unwrap_or_default();
+    [value, value - lower, value + upper]
+}
+
+#[test]
+fn test_item_bounds_with_errors() {
+    let errors = PointErrors::new().y([0.5, 5.0, 0.5]);
+    let points =
+        Points::new("measured", vec![[1.0, 1.0], [2.0, 4.0], [3.0, 9.0]]).error_bars(errors);
+    let log_y = [AxisScale::Linear, AxisScale::Log10];
+
+    // The error below the second point goes past zero, so only the others count on a log axis:
+    let bounds = item_bounds_on_scales(&points, &log_y);
+    assert_eq!((bounds.min[1], bounds.max[1]), (0.5, 9.5));
+
+    let visible = item_bounds_in_range(&points, Axis::X, &(1.5..=2.5), &log_y);
+    assert_eq!((visible.min[1], visible.max[1]), (4.0, 9.0));
+    let visible = item_bounds_in_range(&points, Axis::X, &(1.5..=2.5), &[AxisScale::Linear; 2]);
+    assert_eq!((visible.min[1], visible.max[1]), (-1.0, 9.0));
+}
+
+/// Show the plot built by `plot` for a few frames, so it also runs with the memory of the
+/// previous frame.
+#[cfg(test)]
+fn show_test_plot(plot: impl Fn() -> Plot<'static>, build_fn: impl Fn(&mut PlotUi<'_>)) {
+    let ctx = egui::Context::default();
+    ctx.set_fonts(egui::FontDefinitions::empty());
+    for _ in 0..3 {
+        let output = ctx.run(Default::default(), |ctx| {
+            egui::CentralPanel::default().show(ctx, |ui| {
+                plot().show(ui, &build_fn);
+            });
+        });
+        assert!(!output.shapes.is_empty());
+    }
+}
+
+#[test]
+fn test_every_scale_shows_any_data() {
+    struct Cubic;
+
+    impl Scale for Cubic {
+        fn forward(&self, value: f64) -> f64 {
+            value.powi(3)
+        }
+
+        fn inverse(&self, scaled: f64) -> f64 {
+            scaled.cbrt()
+        }
+    }
+
+    let scales = [
+        AxisScale::Linear,
+        AxisScale::Log10,
+        AxisScale::log(2.0),
+        AxisScale::Log(0.5),
+        AxisScale::Log(1.0),
+        AxisScale::Symlog {
+            linear_threshold: 1.0,
+        },
+        AxisScale::Reciprocal,
+        AxisScale::custom(&Cubic),
+    ];
+    let datasets = [
+        vec![],
+        vec![[-1.0, -2.0], [0.0, 0.0]],
+        vec![[-1.0, 0.0], [3.0, 2.0]],
+    ];
+    for scale in scales {
+        for data in &datasets {
+            show_test_plot(
+                || Plot::new("plot").x_axis_scale(scale).y_axis_scale(scale),
+                |plot_ui| plot_ui.points(Points::new("data", data.clone())),
+            );
+        }
+    }
+
+    let bounds = PlotBounds::from_min_max([-1.0, -2.0], [3.0, 4.0]);
+    for scale in scales {
+        let transform = PlotTransform::new_with_scales(
+            Rect::from_min_size(Pos2::ZERO, Vec2::splat(100.0)),
+            bounds,
+            false,
+            [scale; 2],
+        );
+        let bounds = transform.bounds();
+        assert!(bounds.is_valid(), "{scale:?}: {bounds:?}");
+        for axis in 0..2 {
+            assert!(bounds.min[axis] < bounds.max[axis], "{scale:?}: {bounds:?}");
+        }
+    }
+}
+
+#[test]
+fn test_degenerate_limits() {
+    let data = || Points::new("data", vec![[1.0, 2.0], [5.0, 3.0]]);
+    let plots: [fn() -> Plot<'static>; 4] = [
+        || Plot::new("plot").y_span_limits(0.0..=0.0),
+        || Plot::new("plot").x_limits(3.0..=3.0),
+        || Plot::new("plot").x_span_limits(-5.0..=-1.0),
+        || Plot::new("plot").x_span_limits(0.0..=1.0).include_x(1e9),
+    ];
+    for plot in plots {
+        show_test_plot(plot, |plot_ui| plot_ui.points(data()));
+        show_test_plot(plot, |_| {});
+    }
+}
+
 /// One mark (horizontal or vertical line) in the background grid of a plot.
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub struct GridMark {
@@ -1542,6 +2514,10 @@ pub struct GridMark {
     /// matches the difference between two `value`s precisely, but rather that grid marks of
     /// same thickness have same `step_size`. For example, months can have a different number
     /// of days, but consistently using a `step_size` of 30 days is a valid approximation.
+    ///
+    /// On a non-linear [`AxisScale`] the distance on screen depends on where the mark is,
+    /// so there `step_size` is the distance to the _previous_ mark of the same thickness,
+    /// e.g. `90.0` for the mark at `100.0` on a log-10 axis.
     pub step_size: f64,
 }
 
@@ -1573,6 +2549,59 @@ pub fn log_grid_spacer(log_base: i64) -> GridSpacer<'static> {
     Box::new(step_sizes)
 }
 
+/// Puts grid lines at "nice" numbers: 1, 2 and 5 times a power of 10.
+///
+/// The step size of the main grid lines is picked so that there are about `target_ticks`
+/// of them across the visible range, e.g. every 5 units for a range of `0..35` and `8` ticks.
+/// There are fainter lines at the next nice step below, and stronger lines at the next
+/// nice step above, which is a multiple of the main step.
+/// Tick labels that end up closer together than the [`AxisHints::label_spacing`] are hidden,
+/// so only the stronger lines are labeled when the main steps are too dense.
+///
+/// See [`nice_grid_spacer_with`] for other multipliers than 1, 2 and 5.
+pub fn nice_grid_spacer(target_ticks: usize) -> GridSpacer<'static> {
+    nice_grid_spacer_with(&[1.0, 2.0, 5.0], target_ticks)
+}
+
+/// Like [`nice_grid_spacer`], but with steps at the given multipliers of a power of 10,
+/// e.g. `&[1.0, 2.5, 5.0]`.
+///
+/// Multipliers outside of `1.0..10.0` are ignored, and `1.0` is always included.
+pub fn nice_grid_spacer_with(multipliers: &[f64], target_ticks: usize) -> GridSpacer<'static> {
+    let mut multipliers: Vec<f64> = multipliers
+        .iter()
+        .copied()
+        .filter(|multiplier| (1.0..10.0).contains(multiplier))
+        .chain([1.0])
+        .collect();
+    multipliers.sort_by(|a, b| cmp_f64(*a, *b));
+    multipliers.dedup();
+    let target_ticks = target_ticks.max(1) as f64;
+
+    let step_sizes = move |input: GridInput| -> Vec<GridMark> {
+        let (min, max) = input.bounds;
+        let span = max - min;
+        // handle degenerate cases
+        if input.base_step_size.abs() < f64::EPSILON || !span.is_finite() || span <= 0.0 {
+            return Vec::new();
+        }
+
+        let main = next_nice_step(
+            &multipliers,
+            (span / target_ticks).max(input.base_step_size),
+        );
+        let step_sizes = [
+            nice_divisor(&multipliers, main),
+            main,
+            nice_multiple(&multipliers, main),
+        ];
+
+        generate_marks(step_sizes, input.bounds)
+    };
+
+    Box::new(step_sizes)
+}
+
 /// Splits the grid into uniform-sized spacings (e.g. 100, 25, 1).
 ///
 /// This function should return 3 positive step sizes, designating where the lines in the grid are drawn.
@@ -1602,6 +2631,9 @@ struct PreparedPlot<'a> {
     coordinates_formatter: Option<(Corner, CoordinatesFormatter<'a>)>,
     // axis_formatters: [AxisFormatter; 2],
     transform: PlotTransform,
+
+    /// The transforms of the secondary y axes, by name.
+    secondary_transforms: BTreeMap<String, PlotTransform>,
     show_grid: Vec2b,
     grid_spacing: Rangef,
     grid_spacers: [GridSpacer<'a>; 2],
@@ -1614,14 +2646,33 @@ struct PreparedPlot<'a> {
 }
 
 impl PreparedPlot<'_> {
+    /// The transform to draw the item with, which depends on its y axis.
+    fn item_transform(&self, item: &dyn PlotItem) -> &PlotTransform {
+        item.secondary_y_axis()
+            .and_then(|name| self.secondary_transforms.get(name))
+            .unwrap_or(&self.transform)
+    }
+
     fn ui(self, ui: &mut Ui, response: &Response) -> (Vec<Cursor>, Option<Id>) {
         let mut axes_shapes = Vec::new();
 
-        if self.show_grid.x {
-            self.paint_grid(ui, &mut axes_shapes, Axis::X, self.grid_spacing);
-        }
-        if self.show_grid.y {
-            self.paint_grid(ui, &mut axes_shapes, Axis::Y, self.grid_spacing);
+        if let Some(polar) = self.transform.polar() {
+            polar::paint_polar_grid(
+                ui,
+                &self.transform,
+                polar,
+                self.show_grid,
+                &self.grid_spacers[1],
+                self.grid_spacing,
+                &mut axes_shapes,
+            );
+        } else {
+            if self.show_grid.x {
+                self.paint_grid(ui, &mut axes_shapes, Axis::X, self.grid_spacing);
+            }
+            if self.show_grid.y {
+                self.paint_grid(ui, &mut axes_shapes, Axis::Y, self.grid_spacing);
+            }
         }
 
         // Sort the axes by strength so that those with higher strength are drawn in front.
@@ -1638,7 +2689,7 @@ impl PreparedPlot<'_> {
         );
         plot_ui.set_clip_rect(transform.frame().intersect(ui.clip_rect()));
         for item in &self.items {
-            item.shapes(&plot_ui, transform, &mut shapes);
+            item.shapes(&plot_ui, self.item_transform(&**item), &mut shapes);
         }
 
         let hover_pos = response.hover_pos();
@@ -1653,6 +2704,25 @@ impl PreparedPlot<'_> {
 
         let mut draw_cursor = |cursors: &Vec<Cursor>, always| {
             for &cursor in cursors {
+                if let Some(polar) = transform.polar() {
+                    // Circles of constant radius and rays of constant angle:
+                    let center = polar::screen_center(transform);
+                    match cursor {
+                        Cursor::Horizontal { y } if self.draw_cursor_y || always => {
+                            let radius = (y * transform.dpos_dvalue_x()).abs() as f32;
+                            shapes.push(Shape::circle_stroke(center, radius, (1.0, line_color)));
+                        }
+                        Cursor::Vertical { x } if self.draw_cursor_x || always => {
+                            let length = transform.frame().size().length()
+                                + transform.frame().distance_to_pos(center);
+                            let end =
+                                center + length * polar::screen_direction(transform, polar, x);
+                            shapes.push(Shape::line_segment([center, end], (1.0, line_color)));
+                        }
+                        Cursor::Horizontal { .. } | Cursor::Vertical { .. } => {}
+                    }
+                    continue;
+                }
                 match cursor {
                     Cursor::Horizontal { y } => {
                         if self.draw_cursor_y || always {
@@ -1728,18 +2798,16 @@ impl PreparedPlot<'_> {
         let bounds = transform.bounds();
         let value_cross = 0.0_f64.clamp(bounds.min[1 - iaxis], bounds.max[1 - iaxis]);
 
-        let input = GridInput {
-            bounds: (bounds.min[iaxis], bounds.max[iaxis]),
-            base_step_size: transform.dvalue_dpos()[iaxis].abs() * fade_range.min as f64,
-        };
-        let steps = (grid_spacers[iaxis])(input);
+        let steps = grid_marks(transform, axis, &grid_spacers[iaxis], fade_range.min);
 
         let clamp_range = clamp_grid.then(|| {
             let mut tight_bounds = PlotBounds::NOTHING;
             for item in &self.items {
                 let item_bounds = item.bounds();
                 tight_bounds.merge_x(&item_bounds);
-                tight_bounds.merge_y(&item_bounds);
+                if item.secondary_y_axis().is_none() {
+                    tight_bounds.merge_y(&item_bounds);
+                }
             }
             tight_bounds
         });
@@ -1768,7 +2836,7 @@ impl PreparedPlot<'_> {
             };
 
             let pos_in_gui = transform.position_from_point(&value);
-            let spacing_in_points = (transform.dpos_dvalue()[iaxis] * step.step_size).abs() as f32;
+            let spacing_in_points = transform.spacing_in_points(axis, &step);
 
             if spacing_in_points <= fade_range.min {
                 continue; // Too close together
@@ -1825,7 +2893,7 @@ impl PreparedPlot<'_> {
             .filter(|entry| entry.allow_hover())
             .filter_map(|item| {
                 let item = &**item;
-                let closest = item.find_closest(pointer, transform);
+                let closest = item.find_closest(pointer, self.item_transform(item));
 
                 Some(item).zip(closest)
             });
@@ -1834,16 +2902,17 @@ impl PreparedPlot<'_> {
             .min_by_key(|(_, elem)| elem.dist_sq.ord())
             .filter(|(_, elem)| elem.dist_sq <= interact_radius_sq);
 
-        let plot = items::PlotConfig {
-            ui,
-            transform,
-            show_x: *show_x,
-            show_y: *show_y,
-        };
-
         let mut cursors = Vec::new();
 
         let hovered_plot_item_id = if let Some((item, elem)) = closest {
+            // Report the values in the units of the item's own y axis:
+            let item_transform = self.item_transform(item);
+            let plot = items::PlotConfig {
+                ui,
+                transform: item_transform,
+                show_x: *show_x,
+                show_y: *show_y,
+            };
             item.on_hover(
                 plot_area_response,
                 elem,
@@ -1852,12 +2921,29 @@ impl PreparedPlot<'_> {
                 &plot,
                 label_formatter,
             );
+
+            // The cursors are drawn (and shared with linked plots) in the main y units:
+            if item.secondary_y_axis().is_some() {
+                for cursor in &mut cursors {
+                    if let Cursor::Horizontal { y } = cursor {
+                        let pos = item_transform.position_from_point(&PlotPoint::new(0.0, *y));
+                        *y = transform.value_from_position(pos).y;
+                    }
+                }
+            }
             Some(item.id())
         } else {
+            let plot = items::PlotConfig {
+                ui,
+                transform,
+                show_x: *show_x,
+                show_y: *show_y,
+            };
             let value = transform.value_from_position(pointer);
             items::rulers_and_tooltip_at_value(
                 plot_area_response,
                 value,
+                [None, None],
                 "",
                 &plot,
                 &mut cursors,
@@ -1883,6 +2969,76 @@ fn next_power(value: f64, base: f64) -> f64 {
     base.powi(value.abs().log(base).ceil() as i32)
 }
 
+/// The nice steps (`multiplier × 10^n`) around `value`, in ascending order.
+fn nice_steps_around(multipliers: &[f64], value: f64) -> impl Iterator<Item = f64> + '_ {
+    let exponent = value.abs().log10().floor() as i32;
+    (exponent - 2..=exponent + 2).flat_map(move |exponent| {
+        let power = 10.0_f64.powi(exponent);
+        multipliers.iter().map(move |multiplier| multiplier * power)
+    })
+}
+
+/// The smallest nice step that is at least `value`.
+fn next_nice_step(multipliers: &[f64], value: f64) -> f64 {
+    nice_steps_around(multipliers, value)
+        .find(|&step| value <= step * (1.0 + 1e-9))
+        .unwrap_or(value)
+}
+
+/// Is `step` a whole multiple of `divisor`?
+fn is_multiple_of(step: f64, divisor: f64) -> bool {
+    let ratio = step / divisor;
+    (ratio - ratio.round()).abs() < 1e-6
+}
+
+/// The largest nice step below `step` that divides it.
+fn nice_divisor(multipliers: &[f64], step: f64) -> f64 {
+    let steps: Vec<f64> = nice_steps_around(multipliers, step).collect();
+    steps
+        .into_iter()
+        .rev()
+        .find(|&smaller| smaller < step * (1.0 - 1e-9) && is_multiple_of(step, smaller))
+        .unwrap_or(step / 10.0)
+}
+
+/// The smallest nice step above `step` that is a multiple of it.
+fn nice_multiple(multipliers: &[f64], step: f64) -> f64 {
+    nice_steps_around(multipliers, step)
+        .find(|&larger| step * (1.0 + 1e-9) < larger && is_multiple_of(larger, step))
+        .unwrap_or(step * 10.0)
+}
+
+#[test]
+fn test_nice_grid_spacer() {
+    let steps = |multipliers: &[f64], step| {
+        (
+            nice_divisor(multipliers, step),
+            nice_multiple(multipliers, step),
+        )
+    };
+    let one_two_five = [1.0, 2.0, 5.0];
+    assert_eq!(next_nice_step(&one_two_five, 4.375), 5.0);
+    assert_eq!(next_nice_step(&one_two_five, 5.0), 5.0);
+    assert_eq!(next_nice_step(&one_two_five, 0.0123), 0.02);
+    assert_eq!(next_nice_step(&one_two_five, 7.0), 10.0);
+    assert_eq!(steps(&one_two_five, 5.0), (1.0, 10.0));
+    assert_eq!(steps(&one_two_five, 20.0), (10.0, 100.0));
+    assert_eq!(steps(&one_two_five, 100.0), (50.0, 200.0));
+    assert_eq!(steps(&[1.0, 2.5, 5.0], 2.5), (0.5, 5.0));
+
+    let spacer = nice_grid_spacer(8);
+    let marks = spacer(GridInput {
+        bounds: (-1.0, 36.0),
+        base_step_size: 0.9,
+    });
+    let main_marks: Vec<f64> = marks
+        .iter()
+        .filter(|mark| mark.step_size >= 5.0)
+        .map(|mark| mark.value)
+        .collect();
+    assert_eq!(main_marks, [0.0, 5.0, 10.0, 15.0, 20.0, 25.0, 30.0, 35.0]);
+}
+
 /// Fill in all values between [min, max] which are a multiple of `step_size`
 fn generate_marks(step_sizes: [f64; 3], bounds: (f64, f64)) -> Vec<GridMark> {
     let mut steps = vec![];
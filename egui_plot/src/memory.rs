@@ -1,4 +1,4 @@
-use std::collections::BTreeMap;
+use std::{collections::BTreeMap, ops::RangeInclusive};
 
 use egui::{Context, Id, Pos2, Vec2b};
 
@@ -14,6 +14,13 @@ pub struct PlotMemory {
     /// the bounds, for example by moving or zooming.
     pub auto_bounds: Vec2b,
 
+    /// Indicates if following the newest data (see [`crate::Plot::follow_x`]) is paused.
+    ///
+    /// This is set to `true` whenever the user moves or zooms the x axis,
+    /// and back to `false` when they double-click the plot.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub follow_paused: bool,
+
     /// Hovered legend item if any.
     pub hovered_legend_item: Option<Id>,
 
@@ -32,6 +39,35 @@ pub struct PlotMemory {
     /// in order to fit the labels, if necessary.
     pub(crate) x_axis_thickness: BTreeMap<usize, f32>,
     pub(crate) y_axis_thickness: BTreeMap<usize, f32>,
+
+    /// The y bounds of each secondary y axis, by name.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub(crate) secondary_y_axes: BTreeMap<String, SecondaryAxisMemory>,
+
+    /// The thickness of the tick labels of the colorbar the previous frame.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub(crate) colorbar_thickness: f32,
+}
+
+/// The state of a secondary y axis, see [`crate::AxisHints::secondary_y_axis`].
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) struct SecondaryAxisMemory {
+    pub(crate) min: f64,
+    pub(crate) max: f64,
+
+    /// Whether the bounds are fitted to the items on this axis.
+    pub(crate) auto_bounds: bool,
+}
+
+impl Default for SecondaryAxisMemory {
+    fn default() -> Self {
+        Self {
+            min: f64::INFINITY,
+            max: -f64::INFINITY,
+            auto_bounds: true,
+        }
+    }
 }
 
 impl PlotMemory {
@@ -56,6 +92,24 @@ impl PlotMemory {
     pub fn set_bounds(&mut self, bounds: PlotBounds) {
         self.transform.set_bounds(bounds);
     }
+
+    /// The y bounds of the named secondary y axis, if the plot has it.
+    pub fn secondary_y_bounds(&self, name: &str) -> Option<RangeInclusive<f64>> {
+        let axis = self.secondary_y_axes.get(name)?;
+        Some(axis.min..=axis.max)
+    }
+
+    /// Set the y bounds of the named secondary y axis, turning off its auto-bounds.
+    pub fn set_secondary_y_bounds(&mut self, name: impl Into<String>, range: RangeInclusive<f64>) {
+        self.secondary_y_axes.insert(
+            name.into(),
+            SecondaryAxisMemory {
+                min: *range.start(),
+                max: *range.end(),
+                auto_bounds: false,
+            },
+        );
+    }
 }
 
 #[cfg(feature = "serde")]
@@ -0,0 +1,276 @@
+use egui::{Id, InnerResponse, Rect, Sense, Ui, UiBuilder, Vec2, Vec2b, vec2};
+
+use crate::{Plot, PlotResponse, PlotUi};
+
+/// How far the plot area of a plot is inset from the rectangle of the whole plot widget,
+/// i.e. how much room its axes take on each side.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+struct Insets {
+    left: f32,
+    right: f32,
+    top: f32,
+    bottom: f32,
+}
+
+/// Information about a [`PlotGrid`] that has to persist between frames.
+#[derive(Clone, Debug, Default)]
+struct PlotGridMemory {
+    /// The insets of each cell the previous frame, in row-major order.
+    insets: Vec<Option<Insets>>,
+}
+
+/// Lays out plots in rows and columns, with their plot areas lined up.
+///
+/// Plots in the same column get the same room for their y axes and plots in the same row
+/// the same room for their x axes, even if their tick labels have different widths.
+///
+/// With [`Self::share_axes`], the plots show the same x and/or y range and cursor,
+/// and only the plots in the bottom row (for x) or left column (for y) show the shared axis.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// use egui_plot::{Line, Plot, PlotGrid, PlotPoints};
+///
+/// let temperature = PlotPoints::new(vec![[0.0, 21.0], [1.0, 23.0]]);
+/// let pressure = PlotPoints::new(vec![[0.0, 1013.0], [1.0, 1009.0]]);
+///
+/// // Two plots on top of each other, with the x axis only at the bottom:
+/// PlotGrid::new("dashboard", 2, 1)
+///     .share_axes([true, false])
+///     .show(ui, |grid| {
+///         grid.plot(Plot::new("temperature"), |plot_ui| {
+///             plot_ui.line(Line::new("temperature", temperature));
+///         });
+///         grid.plot(Plot::new("pressure"), |plot_ui| {
+///             plot_ui.line(Line::new("pressure", pressure));
+///         });
+///     });
+/// # });
+/// ```
+pub struct PlotGrid {
+    id_source: Id,
+    num_rows: usize,
+    num_columns: usize,
+    share_axes: Vec2b,
+    spacing: Option<Vec2>,
+    width: Option<f32>,
+    height: Option<f32>,
+}
+
+impl PlotGrid {
+    /// Give a unique id for each grid within the same [`Ui`].
+    pub fn new(id_source: impl std::hash::Hash, num_rows: usize, num_columns: usize) -> Self {
+        Self {
+            id_source: Id::new(id_source),
+            num_rows: num_rows.max(1),
+            num_columns: num_columns.max(1),
+            share_axes: false.into(),
+            spacing: None,
+            width: None,
+            height: None,
+        }
+    }
+
+    /// Share the x and/or y axis between all plots in the grid.
+    ///
+    /// Shared axes are linked (see [`Plot::link_axis`] and [`Plot::link_cursor`]), and only shown
+    /// on the plots in the bottom row (for x) or in the left column (for y).
+    /// This overrides any link groups set on the plots themselves.
+    ///
+    /// Default: `false`.
+    #[inline]
+    pub fn share_axes(mut self, share: impl Into<Vec2b>) -> Self {
+        self.share_axes = share.into();
+        self
+    }
+
+    /// Space between the cells. Default: the item spacing of the [`Ui`].
+    #[inline]
+    pub fn spacing(mut self, spacing: impl Into<Vec2>) -> Self {
+        self.spacing = Some(spacing.into());
+        self
+    }
+
+    /// Width of the whole grid. By default the grid fills the width of the ui it is in.
+    #[inline]
+    pub fn width(mut self, width: f32) -> Self {
+        self.width = Some(width);
+        self
+    }
+
+    /// Height of the whole grid. By default the grid fills the height of the ui it is in.
+    #[inline]
+    pub fn height(mut self, height: f32) -> Self {
+        self.height = Some(height);
+        self
+    }
+
+    /// Add the plots with [`PlotGridUi::plot`], one per cell, row by row.
+    pub fn show<R>(
+        self,
+        ui: &mut Ui,
+        add_contents: impl FnOnce(&mut PlotGridUi<'_>) -> R,
+    ) -> InnerResponse<R> {
+        let id = ui.make_persistent_id(self.id_source);
+        let available = ui.available_size_before_wrap();
+        let size = vec2(
+            self.width.unwrap_or(available.x),
+            self.height.unwrap_or(available.y),
+        );
+        let (rect, response) = ui.allocate_exact_size(size, Sense::hover());
+
+        let spacing = self.spacing.unwrap_or(ui.spacing().item_spacing);
+        let cells = vec2(self.num_columns as f32, self.num_rows as f32);
+        let cell_size = ((size - (cells - Vec2::splat(1.0)) * spacing) / cells).max(Vec2::ZERO);
+
+        let mut memory: PlotGridMemory = ui.data(|data| data.get_temp(id)).unwrap_or_default();
+        memory.insets.resize(self.num_rows * self.num_columns, None);
+
+        let mut grid_ui = PlotGridUi {
+            ui,
+            id,
+            grid: &self,
+            rect,
+            cell_size,
+            spacing,
+            next_cell: 0,
+            memory,
+        };
+        let inner = add_contents(&mut grid_ui);
+
+        let PlotGridUi { ui, memory, .. } = grid_ui;
+        ui.data_mut(|data| data.insert_temp(id, memory));
+
+        InnerResponse::new(inner, response)
+    }
+}
+
+/// Adds plots to the cells of a [`PlotGrid`].
+pub struct PlotGridUi<'g> {
+    ui: &'g mut Ui,
+    id: Id,
+    grid: &'g PlotGrid,
+    rect: Rect,
+    cell_size: Vec2,
+    spacing: Vec2,
+    next_cell: usize,
+    memory: PlotGridMemory,
+}
+
+impl PlotGridUi<'_> {
+    /// Show a plot in the next cell, going row by row.
+    ///
+    /// The size of the plot is set to fit the cell.
+    pub fn plot<'a, 'b, R>(
+        &mut self,
+        plot: Plot<'a>,
+        build_fn: impl FnOnce(&mut PlotUi<'b>) -> R + 'a,
+    ) -> PlotResponse<R> {
+        let index = self.next_cell;
+        self.next_cell += 1;
+        let (num_rows, num_columns) = (self.grid.num_rows, self.grid.num_columns);
+        let (row, column) = (index / num_columns, index % num_columns);
+        if self.memory.insets.len() <= index {
+            self.memory.insets.resize(index + 1, None);
+        }
+
+        let cell_min =
+            self.rect.min + vec2(column as f32, row as f32) * (self.cell_size + self.spacing);
+        let cell = Rect::from_min_size(cell_min, self.cell_size);
+        let padding = alignment_padding(&self.memory.insets, num_columns, index);
+        let plot_rect = Rect::from_min_max(
+            cell.min + vec2(padding.left, padding.top),
+            cell.max - vec2(padding.right, padding.bottom),
+        );
+
+        let mut plot = plot.width(plot_rect.width()).height(plot_rect.height());
+        let share = self.grid.share_axes;
+        if share.any() {
+            plot = plot
+                .link_axis(self.id.with("axes"), share)
+                .link_cursor(self.id.with("cursors"), share);
+            let is_bottom_row = row + 1 >= num_rows;
+            let is_left_column = column == 0;
+            plot.show_axes = plot.show_axes.and(Vec2b::new(
+                !share.x || is_bottom_row,
+                !share.y || is_left_column,
+            ));
+        }
+
+        let mut cell_ui = self.ui.new_child(
+            UiBuilder::new()
+                .id_salt(index)
+                .max_rect(plot_rect)
+                .layout(*self.ui.layout()),
+        );
+        let response = plot.show(&mut cell_ui, build_fn);
+
+        let frame = response.transform.frame();
+        self.memory.insets[index] = Some(Insets {
+            left: frame.min.x - plot_rect.min.x,
+            right: plot_rect.max.x - frame.max.x,
+            top: frame.min.y - plot_rect.min.y,
+            bottom: plot_rect.max.y - frame.max.y,
+        });
+
+        response
+    }
+}
+
+/// How much to shrink the rectangle of the cell at `index` so that its plot area lines up
+/// with the other plot areas in the same row and column.
+fn alignment_padding(insets: &[Option<Insets>], num_columns: usize, index: usize) -> Insets {
+    let Some(Some(own)) = insets.get(index) else {
+        return Insets::default();
+    };
+    let (row, column) = (index / num_columns, index % num_columns);
+    let mut largest = *own;
+    for (other, other_insets) in insets.iter().enumerate() {
+        let Some(other_insets) = other_insets else {
+            continue;
+        };
+        if other % num_columns == column {
+            largest.left = largest.left.max(other_insets.left);
+            largest.right = largest.right.max(other_insets.right);
+        }
+        if other / num_columns == row {
+            largest.top = largest.top.max(other_insets.top);
+            largest.bottom = largest.bottom.max(other_insets.bottom);
+        }
+    }
+    Insets {
+        left: largest.left - own.left,
+        right: largest.right - own.right,
+        top: largest.top - own.top,
+        bottom: largest.bottom - own.bottom,
+    }
+}
+
+#[test]
+fn test_alignment_padding() {
+    let insets = |left, bottom| {
+        Some(Insets {
+            left,
+            right: 0.0,
+            top: 0.0,
+            bottom,
+        })
+    };
+    // A 2×2 grid where the y axis labels of the top left plot are the widest,
+    // and the x axis of the bottom right plot is the tallest:
+    let cells = [
+        insets(40.0, 20.0),
+        insets(30.0, 20.0),
+        insets(25.0, 20.0),
+        insets(30.0, 35.0),
+    ];
+
+    let padding = alignment_padding(&cells, 2, 2);
+    assert_eq!((padding.left, padding.bottom), (15.0, 15.0));
+    let padding = alignment_padding(&cells, 2, 1);
+    assert_eq!((padding.left, padding.bottom), (0.0, 0.0));
+    assert_eq!(
+        alignment_padding(&[None, insets(1.0, 1.0)], 2, 0),
+        Insets::default()
+    );
+}
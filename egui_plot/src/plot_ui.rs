@@ -171,6 +171,32 @@ impl<'a> PlotUi<'a> {
         self.items.push(Box::new(polygon));
     }
 
+    /// Fill the area between two series.
+    pub fn fill_between(&mut self, mut fill_between: crate::FillBetween<'a>) {
+        if fill_between.first.is_empty() || fill_between.second.is_empty() {
+            return;
+        };
+
+        // Give the fill an automatic color if no color has been assigned.
+        if fill_between.color == Color32::TRANSPARENT {
+            fill_between.color = self.auto_color();
+        }
+        self.items.push(Box::new(fill_between));
+    }
+
+    /// Add stacked areas, one item per layer.
+    pub fn stacked_areas(&mut self, areas: crate::StackedAreas) {
+        if areas.is_empty() {
+            return;
+        }
+
+        // Layers without a color get an automatic one.
+        let layers = areas.into_layers(|| self.auto_color());
+        for layer in layers {
+            self.items.push(Box::new(layer));
+        }
+    }
+
     /// Add a text.
     pub fn text(&mut self, text: crate::Text) {
         if text.text.is_empty() {
@@ -193,6 +219,19 @@ impl<'a> PlotUi<'a> {
         self.items.push(Box::new(points));
     }
 
+    /// Add error bars.
+    pub fn error_bars(&mut self, mut error_bars: crate::ErrorBars<'a>) {
+        if error_bars.series.is_empty() {
+            return;
+        };
+
+        // Give the bars an automatic color if no color has been assigned.
+        if error_bars.stroke.color == Color32::TRANSPARENT {
+            error_bars.stroke.color = self.auto_color();
+        }
+        self.items.push(Box::new(error_bars));
+    }
+
     /// Add arrows.
     pub fn arrows(&mut self, mut arrows: crate::Arrows<'a>) {
         if arrows.origins.is_empty() || arrows.tips.is_empty() {
@@ -211,6 +250,23 @@ impl<'a> PlotUi<'a> {
         self.items.push(Box::new(image));
     }
 
+    /// Add a heatmap.
+    pub fn heatmap(&mut self, heatmap: crate::Heatmap) {
+        if heatmap.values.is_empty() {
+            return;
+        }
+        self.items.push(Box::new(heatmap));
+    }
+
+    /// Add contour lines.
+    pub fn contour(&mut self, mut contour: crate::Contour<'a>) {
+        // Give the lines an automatic color if no color has been assigned.
+        if contour.stroke.color == Color32::TRANSPARENT {
+            contour.stroke.color = self.auto_color();
+        }
+        self.items.push(Box::new(contour));
+    }
+
     /// Add a horizontal line.
     /// Can be useful e.g. to show min/max bounds or similar.
     /// Always fills the full width of the plot.
@@ -244,6 +300,40 @@ impl<'a> PlotUi<'a> {
         self.items.push(Box::new(box_plot));
     }
 
+    /// Add a candlestick or OHLC chart.
+    pub fn candlestick_chart(&mut self, chart: crate::CandlestickChart) {
+        if chart.candles.is_empty() {
+            return;
+        }
+        self.items.push(Box::new(chart));
+    }
+
+    /// Add a violin plot.
+    pub fn violin_plot(&mut self, mut violin_plot: crate::ViolinPlot) {
+        if violin_plot.violins.is_empty() {
+            return;
+        }
+
+        // Give the elements an automatic color if no color has been assigned.
+        if violin_plot.default_color == Color32::TRANSPARENT {
+            violin_plot = violin_plot.color(self.auto_color());
+        }
+        self.items.push(Box::new(violin_plot));
+    }
+
+    /// Add a histogram.
+    pub fn histogram(&mut self, mut histogram: crate::Histogram) {
+        if histogram.bars.is_empty() {
+            return;
+        }
+
+        // Give the histogram an automatic color if no color has been assigned.
+        if histogram.color == Color32::TRANSPARENT {
+            histogram = histogram.color(self.auto_color());
+        }
+        self.items.push(Box::new(histogram));
+    }
+
     /// Add a bar chart.
     pub fn bar_chart(&mut self, mut chart: crate::BarChart) {
         if chart.bars.is_empty() {
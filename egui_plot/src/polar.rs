@@ -0,0 +1,304 @@
+use std::f64::consts::TAU;
+
+use egui::{Align2, Pos2, Rangef, Shape, Stroke, TextStyle, Ui, Vec2, Vec2b, pos2, remap_clamp};
+
+use crate::{GridInput, GridSpacer, PlotPoint, PlotTransform, color_from_strength};
+
+/// Number of spokes in the angular grid.
+const SPOKES: usize = 12;
+
+/// Unit of the angles in a polar plot.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum AngleUnit {
+    /// A full turn is 360.
+    #[default]
+    Degrees,
+
+    /// A full turn is 2π.
+    Radians,
+}
+
+/// Layout of a polar plot, see [`crate::Plot::polar`].
+///
+/// In a polar plot the x coordinate of each [`PlotPoint`] is an angle and the y coordinate is
+/// the distance from the center. Items like [`crate::Line`], [`crate::Points`] and
+/// [`crate::Polygon`] are drawn with straight segments between their points,
+/// so lines along a circle need enough points to look round.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct Polar {
+    angle_unit: AngleUnit,
+    clockwise: bool,
+
+    /// Screen direction of angle zero, in radians counter-clockwise from the right.
+    zero_direction: f64,
+}
+
+impl Default for Polar {
+    /// Angles in degrees, counter-clockwise, starting on the right.
+    fn default() -> Self {
+        Self {
+            angle_unit: AngleUnit::Degrees,
+            clockwise: false,
+            zero_direction: 0.0,
+        }
+    }
+}
+
+impl Polar {
+    /// Angles in degrees, clockwise, starting at the top, like on a compass.
+    pub fn compass() -> Self {
+        Self::default().clockwise(true).zero_direction_degrees(90.0)
+    }
+
+    /// Unit of the angles in the data. Default: [`AngleUnit::Degrees`].
+    #[inline]
+    pub fn angle_unit(mut self, angle_unit: AngleUnit) -> Self {
+        self.angle_unit = angle_unit;
+        self
+    }
+
+    /// Whether angles grow clockwise. Default: `false`.
+    #[inline]
+    pub fn clockwise(mut self, clockwise: bool) -> Self {
+        self.clockwise = clockwise;
+        self
+    }
+
+    /// Where on the screen angle zero points, in degrees counter-clockwise from the right,
+    /// so `90.0` points up. Default: `0.0`.
+    #[inline]
+    pub fn zero_direction_degrees(mut self, degrees: f64) -> Self {
+        self.zero_direction = degrees.to_radians();
+        self
+    }
+
+    /// The angle of one full turn, in the angle unit.
+    pub fn full_turn(&self) -> f64 {
+        match self.angle_unit {
+            AngleUnit::Degrees => 360.0,
+            AngleUnit::Radians => TAU,
+        }
+    }
+
+    /// The direction on screen of the given angle, in radians counter-clockwise from the right.
+    fn direction(&self, angle: f64) -> f64 {
+        let radians = angle / self.full_turn() * TAU;
+        if self.clockwise {
+            self.zero_direction - radians
+        } else {
+            self.zero_direction + radians
+        }
+    }
+
+    /// Map `(angle, radius)` to the cartesian plane the plot is laid out on.
+    pub fn cartesian_from_polar(&self, point: &PlotPoint) -> PlotPoint {
+        let (sin, cos) = self.direction(point.x).sin_cos();
+        PlotPoint::new(point.y * cos, point.y * sin)
+    }
+
+    /// The inverse of [`Self::cartesian_from_polar`], with the angle in `0..full_turn`.
+    pub fn polar_from_cartesian(&self, point: &PlotPoint) -> PlotPoint {
+        let radius = point.x.hypot(point.y);
+        let mut radians = point.y.atan2(point.x) - self.zero_direction;
+        if self.clockwise {
+            radians = -radians;
+        }
+        let angle = radians.rem_euclid(TAU) / TAU * self.full_turn();
+        PlotPoint::new(angle, radius)
+    }
+
+    /// Format an angle, e.g. as `45.0°`.
+    pub fn format_angle(&self, angle: f64, num_decimals: usize) -> String {
+        match self.angle_unit {
+            AngleUnit::Degrees => format!("{angle:.num_decimals$}°"),
+            AngleUnit::Radians => format!("{angle:.num_decimals$} rad"),
+        }
+    }
+
+    /// The label of the spoke with the given index.
+    fn spoke_label(&self, spoke: usize) -> String {
+        match self.angle_unit {
+            AngleUnit::Degrees => format!("{}°", spoke * 360 / SPOKES),
+            AngleUnit::Radians => {
+                // Multiples of π/6, in lowest terms:
+                let divisor = gcd(spoke, SPOKES / 2).max(1);
+                let (numerator, denominator) = (spoke / divisor, SPOKES / 2 / divisor);
+                match (numerator, denominator) {
+                    (0, _) => "0".to_owned(),
+                    (1, 1) => "π".to_owned(),
+                    (numerator, 1) => format!("{numerator}π"),
+                    (1, denominator) => format!("π/{denominator}"),
+                    (numerator, denominator) => format!("{numerator}π/{denominator}"),
+                }
+            }
+        }
+    }
+}
+
+fn gcd(a: usize, b: usize) -> usize {
+    if b == 0 { a } else { gcd(b, a % b) }
+}
+
+/// The screen position of the center of a polar plot.
+pub(crate) fn screen_center(transform: &PlotTransform) -> Pos2 {
+    pos2(
+        transform.position_from_point_x(0.0),
+        transform.position_from_point_y(0.0),
+    )
+}
+
+/// The unit vector on screen pointing at the given angle, mirrored like the inverted axes.
+pub(crate) fn screen_direction(transform: &PlotTransform, polar: &Polar, angle: f64) -> Vec2 {
+    let (sin, cos) = polar.direction(angle).sin_cos();
+    let mut direction = Vec2::new(cos as f32, -sin as f32); // negated y axis!
+    if transform.inverted().x {
+        direction.x = -direction.x;
+    }
+    if transform.inverted().y {
+        direction.y = -direction.y;
+    }
+    direction
+}
+
+/// Paint circles of constant radius (if `show_grid.y`) and spokes of constant angle
+/// (if `show_grid.x`), with their labels.
+pub(crate) fn paint_polar_grid(
+    ui: &Ui,
+    transform: &PlotTransform,
+    polar: &Polar,
+    show_grid: Vec2b,
+    radius_spacer: &GridSpacer<'_>,
+    fade_range: Rangef,
+    shapes: &mut Vec<(Shape, f32)>,
+) {
+    let frame = *transform.frame();
+    let center = screen_center(transform);
+    let points_per_unit = transform.dpos_dvalue_x().abs() as f32;
+    let min_visible_radius = if frame.contains(center) {
+        0.0
+    } else {
+        frame.distance_to_pos(center)
+    };
+    let max_visible_radius = [
+        frame.left_top(),
+        frame.right_top(),
+        frame.left_bottom(),
+        frame.right_bottom(),
+    ]
+    .iter()
+    .map(|corner| corner.distance(center))
+    .fold(0.0, f32::max);
+    if !(0.0 < points_per_unit && points_per_unit.is_finite()) {
+        return;
+    }
+
+    let font_id = TextStyle::Body.resolve(ui.style());
+    let mut labels = Vec::new();
+    let mut add_label = |text: String, pos: Pos2, strength: f32| {
+        let color = color_from_strength(ui, strength);
+        let galley = ui.painter().layout_no_wrap(text, font_id.clone(), color);
+        let rect = Align2::CENTER_CENTER.anchor_size(pos, galley.size());
+        if frame.contains_rect(rect) {
+            labels.push((Shape::galley(rect.min, galley, color), strength));
+        }
+    };
+
+    if show_grid.y {
+        let marks = radius_spacer(GridInput {
+            bounds: (
+                (min_visible_radius / points_per_unit) as f64,
+                (max_visible_radius / points_per_unit) as f64,
+            ),
+            base_step_size: (fade_range.min / points_per_unit) as f64,
+        });
+        // Radius labels go between the first two spokes:
+        let label_direction =
+            screen_direction(transform, polar, 0.5 * polar.full_turn() / SPOKES as f64);
+        for mark in marks {
+            let spacing_in_points = mark.step_size as f32 * points_per_unit;
+            if mark.value <= 0.0 || spacing_in_points <= fade_range.min {
+                continue;
+            }
+            let strength = remap_clamp(spacing_in_points, fade_range, 0.0..=1.0);
+            let radius = mark.value as f32 * points_per_unit;
+            shapes.push((
+                Shape::circle_stroke(
+                    center,
+                    radius,
+                    Stroke::new(1.0, color_from_strength(ui, strength)),
+                ),
+                strength,
+            ));
+
+            let num_decimals = (-mark.step_size.log10().round()).max(0.0) as usize;
+            add_label(
+                emath::format_with_decimals_in_range(mark.value, num_decimals..=num_decimals),
+                center + radius * label_direction,
+                strength,
+            );
+        }
+    }
+
+    if show_grid.x {
+        // Angle labels go just inside the largest circle that fits in the frame:
+        let label_radius = frame.contains(center).then(|| {
+            let to_edge = (center - frame.min).min(frame.max - center);
+            to_edge.min_elem() - 16.0
+        });
+        for spoke in 0..SPOKES {
+            let angle = spoke as f64 * polar.full_turn() / SPOKES as f64;
+            let direction = screen_direction(transform, polar, angle);
+            let strength = if spoke % 3 == 0 { 1.0 } else { 0.5 };
+            shapes.push((
+                Shape::line_segment(
+                    [center, center + max_visible_radius * direction],
+                    Stroke::new(1.0, color_from_strength(ui, strength)),
+                ),
+                strength,
+            ));
+            if let Some(label_radius) = label_radius.filter(|radius| 32.0 < *radius) {
+                add_label(
+                    polar.spoke_label(spoke),
+                    center + label_radius * direction,
+                    1.0,
+                );
+            }
+        }
+    }
+
+    // Labels on top of the lines:
+    shapes.extend(labels);
+}
+
+#[test]
+fn test_polar_round_trip() {
+    let compass = Polar::compass();
+    let east = compass.cartesian_from_polar(&PlotPoint::new(90.0, 2.0));
+    assert!((east.x - 2.0).abs() < 1e-9 && east.y.abs() < 1e-9);
+
+    let polar = Polar::default()
+        .angle_unit(AngleUnit::Radians)
+        .zero_direction_degrees(45.0);
+    let point = polar.polar_from_cartesian(&polar.cartesian_from_polar(&PlotPoint::new(4.0, 3.0)));
+    assert!((point.x - 4.0).abs() < 1e-9 && (point.y - 3.0).abs() < 1e-9);
+
+    assert_eq!(polar.spoke_label(4), "2π/3");
+    assert_eq!(polar.spoke_label(6), "π");
+    assert_eq!(Polar::default().spoke_label(3), "90°");
+
+    // Angle zero points right, or left when x is inverted:
+    let frame = egui::Rect::from_min_size(Pos2::ZERO, Vec2::splat(100.0));
+    let bounds = crate::PlotBounds::from_min_max([-1.0, -1.0], [1.0, 1.0]);
+    let transform = PlotTransform::new(frame, bounds, false);
+    assert_eq!(
+        screen_direction(&transform, &Polar::default(), 0.0),
+        Vec2::new(1.0, 0.0)
+    );
+    let inverted = transform.with_inverted([true, false]);
+    assert_eq!(
+        screen_direction(&inverted, &Polar::default(), 0.0),
+        Vec2::new(-1.0, 0.0)
+    );
+}
@@ -0,0 +1,517 @@
+use std::ops::RangeInclusive;
+
+use crate::{GridInput, GridMark, generate_marks, next_power};
+
+/// A custom mapping from values along an axis to the (linear) space in which they are laid out.
+///
+/// Implement this to use your own scale with [`AxisScale::custom`].
+/// The built-in scales are variants of [`AxisScale`].
+pub trait Scale: Send + Sync {
+    /// Map a value in data units to the scaled space.
+    ///
+    /// Must be strictly increasing over the values for which [`Self::is_valid`] is true.
+    /// Values outside of the domain of the scale should result in a non-finite number.
+    fn forward(&self, value: f64) -> f64;
+
+    /// The inverse of [`Self::forward`].
+    fn inverse(&self, scaled: f64) -> f64;
+
+    /// Can this value be shown on this scale?
+    fn is_valid(&self, value: f64) -> bool {
+        value.is_finite() && self.forward(value).is_finite()
+    }
+
+    /// How fast the scaled value changes with the value, i.e. `d forward(value) / d value`.
+    ///
+    /// By default this is estimated numerically.
+    fn derivative(&self, value: f64) -> f64 {
+        let h = value.abs().max(1.0) * 1e-6;
+        let (low, high) = if self.is_valid(value - h) {
+            (value - h, value + h)
+        } else {
+            (value, value + h)
+        };
+        (self.forward(high) - self.forward(low)) / (high - low)
+    }
+
+    /// The range of scaled values an axis shows when there is nothing valid to fit it to,
+    /// e.g. in an empty plot. Both ends must map back to valid values.
+    ///
+    /// Default: `-1.0..=1.0`.
+    fn default_scaled_range(&self) -> RangeInclusive<f64> {
+        -1.0..=1.0
+    }
+
+    /// Grid marks that make sense for this scale.
+    ///
+    /// The default returns `None`, in which case the grid spacer of the plot is applied
+    /// piece by piece along the axis, so the marks get denser where the scale stretches the values.
+    fn grid_marks(&self, input: &GridInput) -> Option<Vec<GridMark>> {
+        _ = input;
+        None
+    }
+}
+
+/// How the values along an axis are mapped to positions on the screen.
+///
+/// Data is always given (and reported back) in its original units;
+/// the scale only changes where on the screen those values end up.
+///
+/// See [`crate::Plot::x_axis_scale`] and [`crate::Plot::y_axis_scale`].
+#[derive(Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum AxisScale {
+    /// Equal distances in value are equal distances on screen. This is the default.
+    Linear,
+
+    /// Every power of ten takes up the same space on the screen.
+    ///
+    /// Only strictly positive values can be shown.
+    Log10,
+
+    /// Like [`Self::Log10`], but with an arbitrary base, see [`Self::log`].
+    ///
+    /// The base must be finite and larger than one; any other base is treated as 10.
+    Log(f64),
+
+    /// Symmetric logarithmic scale: linear within `±linear_threshold`,
+    /// and logarithmic (base 10) further out.
+    ///
+    /// Unlike a log scale, this can show zero and negative values.
+    Symlog {
+        /// Where the linear part around zero ends. Must be positive.
+        linear_threshold: f64,
+    },
+
+    /// Equal distances in `1 / value` are equal distances on screen.
+    ///
+    /// Larger values are still further to the right (or top).
+    /// Only strictly positive values can be shown.
+    Reciprocal,
+
+    /// A user-provided [`Scale`], see [`Self::custom`].
+    ///
+    /// This is skipped when serializing.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    Custom(&'static dyn Scale),
+}
+
+impl Default for AxisScale {
+    fn default() -> Self {
+        Self::Linear
+    }
+}
+
+impl std::fmt::Debug for AxisScale {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Linear => write!(f, "Linear"),
+            Self::Log10 => write!(f, "Log10"),
+            Self::Log(base) => f.debug_tuple("Log").field(base).finish(),
+            Self::Symlog { linear_threshold } => f
+                .debug_struct("Symlog")
+                .field("linear_threshold", linear_threshold)
+                .finish(),
+            Self::Reciprocal => write!(f, "Reciprocal"),
+            Self::Custom(_) => write!(f, "Custom(..)"),
+        }
+    }
+}
+
+impl PartialEq for AxisScale {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Linear, Self::Linear)
+            | (Self::Log10, Self::Log10)
+            | (Self::Reciprocal, Self::Reciprocal) => true,
+            (Self::Log(a), Self::Log(b))
+            | (
+                Self::Symlog {
+                    linear_threshold: a,
+                },
+                Self::Symlog {
+                    linear_threshold: b,
+                },
+            ) => a == b,
+            (Self::Custom(a), Self::Custom(b)) => std::ptr::addr_eq(*a, *b),
+            _ => false,
+        }
+    }
+}
+
+impl AxisScale {
+    /// Use your own [`Scale`] implementation.
+    ///
+    /// The scale is borrowed for the whole program so that [`AxisScale`] (and with it
+    /// [`crate::PlotTransform`]) stays `Copy`. A scale without state can be passed as
+    /// `&MyScale`; one with parameters can live in a `static`, or be leaked once with
+    /// [`Box::leak`] (not every frame).
+    pub fn custom(scale: &'static dyn Scale) -> Self {
+        Self::Custom(scale)
+    }
+
+    /// A logarithmic scale with the given `base`, which must be finite and larger than one.
+    ///
+    /// In release builds, an invalid base is treated as 10.
+    pub fn log(base: f64) -> Self {
+        debug_assert!(
+            is_valid_log_base(base),
+            "The base of a log scale must be finite and larger than one, got {base}"
+        );
+        Self::Log(base)
+    }
+
+    /// Is this the plain [`Self::Linear`] scale?
+    #[inline]
+    pub fn is_linear(&self) -> bool {
+        matches!(self, Self::Linear)
+    }
+
+    /// The logarithmic base, if this is a logarithmic scale.
+    #[inline]
+    pub fn log_base(&self) -> Option<f64> {
+        match self {
+            Self::Log10 => Some(10.0),
+            Self::Log(base) => Some(if is_valid_log_base(*base) {
+                *base
+            } else {
+                10.0
+            }),
+            Self::Linear | Self::Symlog { .. } | Self::Reciprocal | Self::Custom(_) => None,
+        }
+    }
+
+    /// Map a value in data units to the (linear) space in which it is laid out on screen.
+    ///
+    /// Values outside of the domain of the scale (e.g. negative values on a log scale)
+    /// result in a non-finite number.
+    #[inline]
+    pub fn forward(&self, value: f64) -> f64 {
+        match self {
+            Self::Linear => value,
+            Self::Log10 | Self::Log(_) => {
+                log_or_neg_infinity(value, self.log_base().unwrap_or(10.0))
+            }
+            Self::Symlog { linear_threshold } => symlog(value, *linear_threshold),
+            Self::Reciprocal => {
+                if 0.0 < value {
+                    -1.0 / value
+                } else {
+                    f64::NEG_INFINITY
+                }
+            }
+            Self::Custom(scale) => scale.forward(value),
+        }
+    }
+
+    /// The inverse of [`Self::forward`].
+    #[inline]
+    pub fn inverse(&self, scaled: f64) -> f64 {
+        match self {
+            Self::Linear => scaled,
+            Self::Log10 | Self::Log(_) => self.log_base().unwrap_or(10.0).powf(scaled),
+            Self::Symlog { linear_threshold } => symlog_inverse(scaled, *linear_threshold),
+            Self::Reciprocal => -1.0 / scaled,
+            Self::Custom(scale) => scale.inverse(scaled),
+        }
+    }
+
+    /// Can this value be shown on this scale?
+    #[inline]
+    pub fn is_valid(&self, value: f64) -> bool {
+        match self {
+            Self::Linear | Self::Symlog { .. } => value.is_finite(),
+            Self::Log10 | Self::Log(_) | Self::Reciprocal => value.is_finite() && 0.0 < value,
+            Self::Custom(scale) => scale.is_valid(value),
+        }
+    }
+
+    /// How fast the scaled value changes with the value, i.e. `d forward(value) / d value`.
+    #[inline]
+    pub fn derivative(&self, value: f64) -> f64 {
+        match self {
+            Self::Linear => 1.0,
+            Self::Log10 | Self::Log(_) => 1.0 / (value * self.log_base().unwrap_or(10.0).ln()),
+            Self::Symlog { linear_threshold } => {
+                if value.abs() <= *linear_threshold {
+                    1.0 / linear_threshold
+                } else {
+                    1.0 / (value.abs() * std::f64::consts::LN_10)
+                }
+            }
+            Self::Reciprocal => 1.0 / (value * value),
+            Self::Custom(scale) => scale.derivative(value),
+        }
+    }
+
+    /// The range of scaled values an axis shows when there is nothing valid to fit it to,
+    /// see [`Scale::default_scaled_range`].
+    pub fn default_scaled_range(&self) -> RangeInclusive<f64> {
+        match self {
+            Self::Linear | Self::Log10 | Self::Log(_) | Self::Symlog { .. } => -1.0..=1.0,
+            // Only negative scaled values map back to (positive) values:
+            Self::Reciprocal => -2.0..=-0.5,
+            Self::Custom(scale) => scale.default_scaled_range(),
+        }
+    }
+
+    /// Can the scaled range `min..=max` be shown on this scale, i.e. do both ends map back
+    /// to valid values, in the right order?
+    pub(crate) fn is_valid_scaled_range(&self, min: f64, max: f64) -> bool {
+        let (min, max) = (self.inverse(min), self.inverse(max));
+        self.is_valid(min) && self.is_valid(max) && min < max
+    }
+
+    /// Grid marks that make sense for this scale.
+    ///
+    /// Returns `None` if the grid spacer of the plot should be used instead.
+    pub(crate) fn grid_marks(&self, input: &GridInput) -> Option<Vec<GridMark>> {
+        match self {
+            Self::Log10 | Self::Log(_) => self.log_base().map(|base| log_scale_marks(base, input)),
+            Self::Symlog { linear_threshold } => Some(symlog_marks(*linear_threshold, input)),
+            Self::Custom(scale) => scale.grid_marks(input),
+            Self::Linear | Self::Reciprocal => None,
+        }
+    }
+}
+
+#[inline]
+fn is_valid_log_base(base: f64) -> bool {
+    base.is_finite() && 1.0 < base
+}
+
+#[inline]
+fn log_or_neg_infinity(value: f64, base: f64) -> f64 {
+    if 0.0 < value {
+        value.log(base)
+    } else {
+        f64::NEG_INFINITY
+    }
+}
+
+#[inline]
+fn symlog(value: f64, linear_threshold: f64) -> f64 {
+    if value.abs() <= linear_threshold {
+        value / linear_threshold
+    } else {
+        value.signum() * (1.0 + (value.abs() / linear_threshold).log10())
+    }
+}
+
+#[inline]
+fn symlog_inverse(scaled: f64, linear_threshold: f64) -> f64 {
+    if scaled.abs() <= 1.0 {
+        scaled * linear_threshold
+    } else {
+        scaled.signum() * linear_threshold * 10.0_f64.powf(scaled.abs() - 1.0)
+    }
+}
+
+/// Sort the marks by value and merge marks at (almost) the same value,
+/// keeping the largest step size.
+pub(crate) fn sort_and_dedup_marks(marks: &mut Vec<GridMark>) {
+    marks.sort_by(|a, b| a.value.total_cmp(&b.value));
+    marks.dedup_by(|next, kept| {
+        let same = (next.value - kept.value).abs() <= 1e-9 * next.value.abs().max(kept.value.abs());
+        if same {
+            kept.step_size = kept.step_size.max(next.step_size);
+        }
+        same
+    });
+}
+
+/// Grid marks for a symmetric log axis: linear marks within the threshold,
+/// and mirrored logarithmic marks outside of it.
+fn symlog_marks(linear_threshold: f64, input: &GridInput) -> Vec<GridMark> {
+    let (min, max) = input.bounds;
+    if !(min < max && min.is_finite() && max.is_finite() && 0.0 < linear_threshold) {
+        return Vec::new();
+    }
+
+    let mut marks = Vec::new();
+
+    let linear_bounds = (min.max(-linear_threshold), max.min(linear_threshold));
+    if linear_bounds.0 < linear_bounds.1 {
+        let steps = [
+            linear_threshold / 10.0,
+            linear_threshold,
+            linear_threshold * 10.0,
+        ];
+        marks.extend(generate_marks(steps, linear_bounds));
+    }
+
+    if linear_threshold < max {
+        let positive = GridInput {
+            bounds: (min.max(linear_threshold), max),
+            base_step_size: input.base_step_size,
+        };
+        marks.extend(log_scale_marks(10.0, &positive));
+    }
+
+    if min < -linear_threshold {
+        let negative = GridInput {
+            bounds: ((-max).max(linear_threshold), -min),
+            base_step_size: input.base_step_size,
+        };
+        marks.extend(
+            log_scale_marks(10.0, &negative)
+                .into_iter()
+                .map(|mark| GridMark {
+                    value: -mark.value,
+                    step_size: mark.step_size,
+                }),
+        );
+    }
+
+    sort_and_dedup_marks(&mut marks);
+    marks
+}
+
+/// Grid marks for a logarithmic axis.
+///
+/// Every power of the base gets a mark, with even stronger marks every few powers so that
+/// labels remain readable when many orders of magnitude are visible.
+/// For integer bases, intermediate marks are placed at 2, 3, … times each power.
+///
+/// When less than one order of magnitude is visible, the marks are the same as on a linear axis.
+fn log_scale_marks(base: f64, input: &GridInput) -> Vec<GridMark> {
+    let (min, max) = input.bounds;
+    if !(0.0 < min && min < max && max.is_finite() && 1.0 < base) {
+        return Vec::new();
+    }
+
+    let first_power = min.log(base).floor() as i32;
+    let last_power = max.log(base).ceil() as i32;
+
+    if last_power - first_power <= 1 && input.base_step_size.is_finite() {
+        // Zoomed in to within a single order of magnitude: fall back to linear marks.
+        if input.base_step_size <= 0.0 {
+            return Vec::new();
+        }
+        let smallest_visible_unit = next_power(input.base_step_size, 10.0);
+        let step_sizes = [
+            smallest_visible_unit,
+            smallest_visible_unit * 10.0,
+            smallest_visible_unit * 100.0,
+        ];
+        return generate_marks(step_sizes, input.bounds);
+    }
+
+    // Marks at powers of the base get a step size spanning `stride` powers,
+    // so that every 5th and 10th power stand out.
+    const STRIDES: [i32; 3] = [1, 5, 10];
+
+    let mut marks = Vec::new();
+    for power in first_power..=last_power {
+        let value = base.powi(power);
+
+        let stride = STRIDES
+            .iter()
+            .rev()
+            .copied()
+            .find(|stride| power % stride == 0)
+            .unwrap_or(1);
+        if min <= value && value <= max {
+            marks.push(GridMark {
+                value,
+                step_size: value - value / base.powi(stride),
+            });
+        }
+
+        if base.fract() == 0.0 {
+            for multiple in 2..(base as i64) {
+                let value = multiple as f64 * base.powi(power);
+                if min <= value && value <= max {
+                    marks.push(GridMark {
+                        value,
+                        step_size: base.powi(power),
+                    });
+                }
+            }
+        }
+    }
+    marks
+}
+
+#[test]
+fn test_log_scale_marks() {
+    let input = GridInput {
+        bounds: (0.5, 200.0),
+        base_step_size: 1.0,
+    };
+    let marks = log_scale_marks(10.0, &input);
+
+    let values: Vec<f64> = marks.iter().map(|mark| mark.value).collect();
+    for expected in [1.0, 10.0, 100.0, 0.6, 0.9, 2.0, 50.0, 200.0] {
+        assert!(
+            values.iter().any(|value| (value - expected).abs() < 1e-9),
+            "Missing mark at {expected}: {values:?}"
+        );
+    }
+
+    // Powers of the base are stronger than the marks in between:
+    let step_size_at = |value: f64| {
+        marks
+            .iter()
+            .find(|mark| (mark.value - value).abs() < 1e-9)
+            .map(|mark| mark.step_size)
+    };
+    assert_eq!(step_size_at(100.0), Some(90.0));
+    assert_eq!(step_size_at(20.0), Some(10.0));
+
+    let scale = AxisScale::Log10;
+    assert!((scale.inverse(scale.forward(123.0)) - 123.0).abs() < 1e-9);
+    assert!(!scale.is_valid(0.0));
+
+    let scale = AxisScale::log(2.0);
+    assert!((scale.forward(8.0) - 3.0).abs() < 1e-9);
+
+    // A base that would reverse the axis (or make it degenerate) is treated as 10:
+    for base in [0.5, 1.0, 0.0, -2.0, f64::NAN, f64::INFINITY] {
+        let scale = AxisScale::Log(base);
+        assert_eq!(scale.log_base(), Some(10.0), "{base}");
+        assert!((scale.forward(100.0) - 2.0).abs() < 1e-9, "{base}");
+        assert!((scale.inverse(2.0) - 100.0).abs() < 1e-9, "{base}");
+        assert!(0.0 < scale.derivative(100.0), "{base}");
+    }
+}
+
+#[test]
+fn test_scales_round_trip() {
+    struct Cubic;
+
+    impl Scale for Cubic {
+        fn forward(&self, value: f64) -> f64 {
+            value.powi(3)
+        }
+
+        fn inverse(&self, scaled: f64) -> f64 {
+            scaled.cbrt()
+        }
+    }
+
+    let scales = [
+        AxisScale::Symlog {
+            linear_threshold: 1.0,
+        },
+        AxisScale::Reciprocal,
+        AxisScale::custom(&Cubic),
+    ];
+    for scale in &scales {
+        for value in [0.25, 0.5, 3.0, 1000.0] {
+            let round_trip = scale.inverse(scale.forward(value));
+            assert!((round_trip - value).abs() < 1e-9, "{scale:?}: {value}");
+            assert!(0.0 < scale.derivative(value), "{scale:?}: {value}");
+            assert!(
+                scale.forward(value) < scale.forward(value * 1.01),
+                "{scale:?}"
+            );
+        }
+    }
+
+    let symlog = &scales[0];
+    assert!((symlog.forward(-1000.0) + 4.0).abs() < 1e-9);
+    assert!((symlog.forward(0.5) - 0.5).abs() < 1e-9);
+    assert!(!AxisScale::Reciprocal.is_valid(0.0));
+    assert!((AxisScale::custom(&Cubic).derivative(2.0) - 12.0).abs() < 1e-6);
+}
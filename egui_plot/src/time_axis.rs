@@ -0,0 +1,462 @@
+use std::ops::RangeInclusive;
+
+use crate::{
+    Axis, AxisHints, CoordinatesFormatter, GridInput, GridMark, GridSpacer, PlotPoint,
+    generate_marks, next_power, scale::sort_and_dedup_marks,
+};
+
+const MINUTE: f64 = 60.0;
+const HOUR: f64 = 60.0 * MINUTE;
+const DAY: f64 = 24.0 * HOUR;
+const WEEK: f64 = 7.0 * DAY;
+
+/// Nominal length of a month, used as the `step_size` of monthly grid marks.
+const MONTH: f64 = 30.0 * DAY;
+
+/// Nominal length of a year, used as the `step_size` of yearly grid marks.
+const YEAR: f64 = 365.0 * DAY;
+
+/// Timestamps further from the epoch than this (about three billion years)
+/// are not shown as dates, so the calendar arithmetic can't overflow.
+const MAX_TIMESTAMP: f64 = 1e17;
+
+const MONTH_NAMES: [&str; 12] = [
+    "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+];
+
+/// Distance between two grid marks on a time axis.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum TimeStep {
+    /// A fixed number of seconds, aligned to multiples of it in local time.
+    Seconds(f64),
+
+    /// Weeks, starting on Mondays.
+    Weeks,
+
+    /// A number of months, starting on the first of the month.
+    Months(i64),
+
+    /// A number of years, starting on the first of January.
+    Years(i64),
+}
+
+impl TimeStep {
+    fn nominal_seconds(self) -> f64 {
+        match self {
+            Self::Seconds(seconds) => seconds,
+            Self::Weeks => WEEK,
+            Self::Months(months) => months as f64 * MONTH,
+            Self::Years(years) => years as f64 * YEAR,
+        }
+    }
+}
+
+/// The step sizes a time axis can use, from fine to coarse.
+///
+/// Each step is followed by the next two for the fainter/stronger grid lines.
+const TIME_STEPS: [TimeStep; 26] = [
+    TimeStep::Seconds(1.0),
+    TimeStep::Seconds(5.0),
+    TimeStep::Seconds(15.0),
+    TimeStep::Seconds(30.0),
+    TimeStep::Seconds(MINUTE),
+    TimeStep::Seconds(5.0 * MINUTE),
+    TimeStep::Seconds(15.0 * MINUTE),
+    TimeStep::Seconds(30.0 * MINUTE),
+    TimeStep::Seconds(HOUR),
+    TimeStep::Seconds(3.0 * HOUR),
+    TimeStep::Seconds(6.0 * HOUR),
+    TimeStep::Seconds(12.0 * HOUR),
+    TimeStep::Seconds(DAY),
+    TimeStep::Weeks,
+    TimeStep::Months(1),
+    TimeStep::Months(3),
+    TimeStep::Months(6),
+    TimeStep::Years(1),
+    TimeStep::Years(5),
+    TimeStep::Years(10),
+    TimeStep::Years(50),
+    TimeStep::Years(100),
+    TimeStep::Years(500),
+    TimeStep::Years(1000),
+    TimeStep::Years(5000),
+    TimeStep::Years(10000),
+];
+
+/// A calendar date and time of day.
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct DateTime {
+    year: i64,
+    month: u32,
+    day: u32,
+    hour: u32,
+    minute: u32,
+
+    /// Seconds within the minute, including the fractional part.
+    second: f64,
+}
+
+impl DateTime {
+    fn is_midnight(&self) -> bool {
+        self.hour == 0 && self.minute == 0 && self.second == 0.0
+    }
+}
+
+/// Calendar-aware grid marks and labels for axes that show unix timestamps, in seconds.
+///
+/// Grid lines are placed at whole seconds, minutes, hours, days, weeks, months or years,
+/// depending on the zoom level, and labels show as much of the date and time as is relevant,
+/// e.g. `14:00` with `Mar 5` on day boundaries.
+///
+/// Times are shown at a fixed offset from UTC (UTC by default), so no timezone database is needed.
+///
+/// The quickest way to use this is [`crate::Plot::x_axis_time`]. The individual parts are
+/// available through [`Self::grid_spacer`], [`Self::axis_hints`], [`Self::label_formatter`]
+/// and [`Self::coordinates_formatter`].
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// use egui_plot::{Plot, TimeAxis};
+///
+/// // Central European Time:
+/// let time_axis = TimeAxis::default().utc_offset_minutes(60);
+/// Plot::new("temperature")
+///     .x_axis_time(time_axis)
+///     .show(ui, |plot_ui| {});
+/// # });
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct TimeAxis {
+    utc_offset_seconds: i64,
+}
+
+impl TimeAxis {
+    /// Show times as UTC plus the given number of minutes, e.g. `330` for UTC+05:30.
+    #[inline]
+    pub fn utc_offset_minutes(mut self, minutes: i32) -> Self {
+        self.utc_offset_seconds = i64::from(minutes) * 60;
+        self
+    }
+
+    /// Grid marks at calendar-aligned steps, for [`crate::Plot::x_grid_spacer`]
+    /// or [`crate::Plot::y_grid_spacer`].
+    pub fn grid_spacer(self) -> GridSpacer<'static> {
+        Box::new(move |input| self.grid_marks(&input))
+    }
+
+    /// An axis showing the grid marks of [`Self::grid_spacer`] as dates and times.
+    pub fn axis_hints(self, axis: Axis) -> AxisHints<'static> {
+        AxisHints::new(axis).formatter(move |mark, range| self.format_mark(mark, range))
+    }
+
+    /// A label for the hover tooltip, see [`crate::Plot::label_formatter`].
+    ///
+    /// Expects the timestamps on the x axis.
+    pub fn label_formatter(self) -> impl Fn(&str, &PlotPoint) -> String + 'static {
+        move |name, value| {
+            let prefix = if name.is_empty() {
+                String::new()
+            } else {
+                format!("{name}\n")
+            };
+            format!(
+                "{prefix}x = {}\ny = {:.3}",
+                self.format_timestamp(value.x),
+                value.y
+            )
+        }
+    }
+
+    /// Show the pointer coordinates, with the timestamp on the x axis.
+    pub fn coordinates_formatter(self) -> CoordinatesFormatter<'static> {
+        CoordinatesFormatter::new(move |value, _| {
+            format!("x: {}\ny: {:.3}", self.format_timestamp(value.x), value.y)
+        })
+    }
+
+    /// Format a timestamp as e.g. `2024-03-05 14:03:07`.
+    ///
+    /// Timestamps billions of years away from 1970 are formatted as plain numbers.
+    pub fn format_timestamp(&self, timestamp: f64) -> String {
+        if !timestamp.is_finite() || MAX_TIMESTAMP < timestamp.abs() {
+            return timestamp.to_string();
+        }
+        let DateTime {
+            year,
+            month,
+            day,
+            hour,
+            minute,
+            second,
+        } = self.date_time(timestamp.round());
+        format!("{year:04}-{month:02}-{day:02} {hour:02}:{minute:02}:{second:02.0}")
+    }
+
+    /// Format a grid mark, showing only as much of the date and time as its step size calls for.
+    pub fn format_mark(&self, mark: GridMark, _range: &RangeInclusive<f64>) -> String {
+        if !mark.value.is_finite() {
+            return String::new();
+        }
+        if MAX_TIMESTAMP < mark.value.abs() {
+            return mark.value.to_string();
+        }
+
+        let value = if 1.0 <= mark.step_size {
+            // Marks are computed in floating point and can be a hair off the whole second.
+            mark.value.round()
+        } else {
+            mark.value
+        };
+        let time = self.date_time(value);
+        let month_name = MONTH_NAMES[time.month as usize - 1];
+
+        if (time.is_midnight() && time.month == 1 && time.day == 1) || YEAR <= mark.step_size {
+            format!("{}", time.year)
+        } else if MONTH <= mark.step_size {
+            month_name.to_owned()
+        } else if time.is_midnight() || DAY <= mark.step_size {
+            format!("{month_name} {}", time.day)
+        } else if MINUTE <= mark.step_size {
+            format!("{:02}:{:02}", time.hour, time.minute)
+        } else if 1.0 <= mark.step_size {
+            format!("{:02}:{:02}:{:02.0}", time.hour, time.minute, time.second)
+        } else {
+            let decimals = (-mark.step_size.log10()).ceil().max(1.0) as usize;
+            format!(
+                "{:02}:{:02}:{:0width$.decimals$}",
+                time.hour,
+                time.minute,
+                time.second,
+                width = decimals + 3,
+            )
+        }
+    }
+
+    fn grid_marks(&self, input: &GridInput) -> Vec<GridMark> {
+        let (min, max) = input.bounds;
+        let base_step_size = input.base_step_size.abs();
+        if !(min.is_finite() && max.is_finite() && min <= max && 0.0 < base_step_size) {
+            return Vec::new();
+        }
+
+        // Below a second there are no calendar units, so use decimal steps.
+        let smallest_decimal_unit = next_power(base_step_size, 10.0);
+        if smallest_decimal_unit < 1.0 {
+            let step_sizes = [
+                smallest_decimal_unit,
+                smallest_decimal_unit * 10.0,
+                smallest_decimal_unit * 100.0,
+            ];
+            return generate_marks(step_sizes, input.bounds);
+        }
+
+        let Some(first) = TIME_STEPS
+            .iter()
+            .position(|step| base_step_size <= step.nominal_seconds())
+        else {
+            // More than ten thousand years between grid lines: just use decimal years.
+            let smallest_unit = next_power(base_step_size / YEAR, 10.0) * YEAR;
+            let step_sizes = [smallest_unit, smallest_unit * 10.0, smallest_unit * 100.0];
+            return generate_marks(step_sizes, input.bounds);
+        };
+
+        let mut marks = Vec::new();
+        for step in TIME_STEPS.iter().skip(first).take(3) {
+            self.fill_marks(&mut marks, *step, input.bounds);
+        }
+        sort_and_dedup_marks(&mut marks);
+        marks
+    }
+
+    fn fill_marks(&self, out: &mut Vec<GridMark>, step: TimeStep, (min, max): (f64, f64)) {
+        let offset = self.utc_offset_seconds as f64;
+        let (local_min, local_max) = (
+            (min + offset).clamp(-MAX_TIMESTAMP, MAX_TIMESTAMP),
+            (max + offset).clamp(-MAX_TIMESTAMP, MAX_TIMESTAMP),
+        );
+        let step_size = step.nominal_seconds();
+        let mut push_local = |local: f64| {
+            if local_min <= local && local <= local_max {
+                out.push(GridMark {
+                    value: local - offset,
+                    step_size,
+                });
+            }
+        };
+
+        match step {
+            TimeStep::Seconds(seconds) => {
+                let first = (local_min / seconds).ceil() as i64;
+                let last = (local_max / seconds).floor() as i64;
+                for i in first..=last {
+                    push_local(i as f64 * seconds);
+                }
+            }
+            TimeStep::Weeks => {
+                // The unix epoch was on a Thursday, so Mondays are 3 days before multiples of 7.
+                let first = ((local_min / DAY + 3.0) / 7.0).ceil() as i64;
+                let last = ((local_max / DAY + 3.0) / 7.0).floor() as i64;
+                for i in first..=last {
+                    push_local((7 * i - 3) as f64 * DAY);
+                }
+            }
+            TimeStep::Months(months) => {
+                let (year, month, _) = civil_from_days((local_min / DAY).floor() as i64);
+                let first = year * 12 + i64::from(month) - 1;
+                let mut index = first + (months - first.rem_euclid(months)) % months;
+                loop {
+                    let days =
+                        days_from_civil(index.div_euclid(12), index.rem_euclid(12) as u32 + 1, 1);
+                    let local = days as f64 * DAY;
+                    if local_max < local {
+                        break;
+                    }
+                    push_local(local);
+                    index += months;
+                }
+            }
+            TimeStep::Years(years) => {
+                let (first, _, _) = civil_from_days((local_min / DAY).floor() as i64);
+                let mut year = first + (years - first.rem_euclid(years)) % years;
+                loop {
+                    let local = days_from_civil(year, 1, 1) as f64 * DAY;
+                    if local_max < local {
+                        break;
+                    }
+                    push_local(local);
+                    year += years;
+                }
+            }
+        }
+    }
+
+    /// The local date and time of a timestamp.
+    fn date_time(&self, timestamp: f64) -> DateTime {
+        let local =
+            (timestamp + self.utc_offset_seconds as f64).clamp(-MAX_TIMESTAMP, MAX_TIMESTAMP);
+        let days = (local / DAY).floor();
+        let seconds_of_day = local - days * DAY;
+        let (year, month, day) = civil_from_days(days as i64);
+        let whole_seconds = seconds_of_day.floor() as u32;
+        DateTime {
+            year,
+            month,
+            day,
+            hour: whole_seconds / 3600,
+            minute: whole_seconds / 60 % 60,
+            second: seconds_of_day - f64::from(whole_seconds - whole_seconds % 60),
+        }
+    }
+}
+
+/// Number of days since 1970-01-01 of the given date in the proleptic Gregorian calendar.
+///
+/// See <https://howardhinnant.github.io/date_algorithms.html#days_from_civil>.
+fn days_from_civil(year: i64, month: u32, day: u32) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year.rem_euclid(400);
+    let month = i64::from(month);
+    let day_of_year = (153 * (month + if 2 < month { -3 } else { 9 }) + 2) / 5 + i64::from(day) - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+/// The inverse of [`days_from_civil`]: `(year, month, day)`.
+///
+/// See <https://howardhinnant.github.io/date_algorithms.html#civil_from_days>.
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let shifted_month = (5 * day_of_year + 2) / 153;
+    let day = (day_of_year - (153 * shifted_month + 2) / 5 + 1) as u32;
+    let month = if shifted_month < 10 {
+        shifted_month + 3
+    } else {
+        shifted_month - 9
+    } as u32;
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+    (year, month, day)
+}
+
+#[test]
+fn test_time_axis() {
+    assert_eq!(days_from_civil(1970, 1, 1), 0);
+    assert_eq!(days_from_civil(2000, 3, 1), 11_017);
+    for days in [-800_000, -1, 0, 59, 11_016, 19_782, 1_000_000] {
+        let (year, month, day) = civil_from_days(days);
+        assert_eq!(days_from_civil(year, month, day), days);
+    }
+
+    // 2024-03-05 14:00:00 UTC
+    let timestamp = 1_709_647_200.0;
+    let utc = TimeAxis::default();
+    assert_eq!(utc.format_timestamp(timestamp), "2024-03-05 14:00:00");
+    let india = utc.utc_offset_minutes(330);
+    assert_eq!(india.format_timestamp(timestamp), "2024-03-05 19:30:00");
+
+    let mark = |value, step_size| GridMark { value, step_size };
+    let range = 0.0..=1.0;
+    assert_eq!(utc.format_mark(mark(timestamp, HOUR), &range), "14:00");
+    assert_eq!(
+        utc.format_mark(mark(timestamp - 14.0 * HOUR, HOUR), &range),
+        "Mar 5"
+    );
+    assert_eq!(
+        utc.format_mark(mark(timestamp + 7.0, 1.0), &range),
+        "14:00:07"
+    );
+    assert_eq!(utc.format_mark(mark(timestamp, MONTH), &range), "Mar");
+
+    // Monthly marks land on the first of each month, in local time:
+    let input = GridInput {
+        bounds: (timestamp, timestamp + 100.0 * DAY),
+        base_step_size: 20.0 * DAY,
+    };
+    let months: Vec<String> = india
+        .grid_marks(&input)
+        .iter()
+        .map(|mark| india.format_timestamp(mark.value))
+        .collect();
+    assert_eq!(
+        months,
+        [
+            "2024-04-01 00:00:00",
+            "2024-05-01 00:00:00",
+            "2024-06-01 00:00:00"
+        ]
+    );
+
+    // Far away from the epoch there are no dates, but also no overflow:
+    assert_eq!(utc.format_timestamp(1e25), 1e25.to_string());
+    assert_eq!(
+        utc.format_mark(mark(-1e26, YEAR), &range),
+        (-1e26).to_string()
+    );
+    for (min, max) in [
+        (-1e26, 0.0),
+        (-1e26, 1e26),
+        (1e25, 1e25 + 1e12),
+        (-1e25 - 1e9, -1e25),
+    ] {
+        for divisions in [3.0, 10.0] {
+            let input = GridInput {
+                bounds: (min, max),
+                base_step_size: (max - min) / divisions,
+            };
+            for mark in utc.grid_marks(&input) {
+                let _label = utc.format_mark(mark, &range);
+            }
+        }
+    }
+    for x in [-1e26, 1e26] {
+        crate::show_test_plot(
+            || crate::Plot::new("plot").x_axis_time(utc).include_x(x),
+            |_| {},
+        );
+    }
+}
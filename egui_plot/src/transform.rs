@@ -2,7 +2,7 @@ use std::ops::RangeInclusive;
 
 use egui::{Pos2, Rect, Vec2, Vec2b, pos2, remap};
 
-use crate::Axis;
+use crate::{Axis, AxisScale, GridMark, Polar};
 
 use super::PlotPoint;
 
@@ -276,16 +276,48 @@ pub struct PlotTransform {
 
     /// Whether to always center the x-range or y-range of the bounds.
     centered: Vec2b,
+
+    /// How values are mapped to the screen along the x and y axes.
+    ///
+    /// Not serialized, since [`AxisScale::Custom`] can't be; the plot sets them every frame.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    scales: [AxisScale; 2],
+
+    /// Whether the x axis grows to the left and/or the y axis grows downwards.
+    #[cfg_attr(feature = "serde", serde(default))]
+    inverted: Vec2b,
+
+    /// If set, data points are `(angle, radius)` and are projected onto the cartesian plane
+    /// that the bounds refer to.
+    #[cfg_attr(feature = "serde", serde(default))]
+    polar: Option<Polar>,
 }
 
 impl PlotTransform {
     pub fn new(frame: Rect, bounds: PlotBounds, center_axis: impl Into<Vec2b>) -> Self {
+        Self::new_with_scales(frame, bounds, center_axis, Default::default())
+    }
+
+    /// Like [`Self::new`], but with a non-linear [`AxisScale`] for the x and/or y axis.
+    ///
+    /// The bounds are sanitized in the scaled space, so e.g. a logarithmic axis
+    /// always ends up with strictly positive bounds.
+    pub fn new_with_scales(
+        frame: Rect,
+        bounds: PlotBounds,
+        center_axis: impl Into<Vec2b>,
+        scales: [AxisScale; 2],
+    ) -> Self {
         debug_assert!(
             0.0 <= frame.width() && 0.0 <= frame.height(),
             "Bad plot frame: {frame:?}"
         );
         let center_axis = center_axis.into();
 
+        // All sanitization happens in the scaled space, where the axes are linear.
+        let bounds_in_data_space = bounds;
+        let bounds = bounds_to_scaled(&bounds_in_data_space, &scales);
+
         // Since the current Y bounds an affect the final X bounds and vice versa, we need to keep
         // the original version of the `bounds` before we start modifying it.
         let mut new_bounds = bounds;
@@ -329,18 +361,83 @@ impl PlotTransform {
             new_bounds.make_y_symmetrical();
         };
 
+        // What is left outside of the domain of a scale (e.g. zero or less on a reciprocal axis)
+        // falls back to a range the scale can show:
+        for (axis, scale) in scales.iter().enumerate() {
+            if !scale.is_valid_scaled_range(new_bounds.min[axis], new_bounds.max[axis]) {
+                let range = scale.default_scaled_range();
+                new_bounds.min[axis] = *range.start();
+                new_bounds.max[axis] = *range.end();
+            }
+        }
+
         debug_assert!(
             new_bounds.is_valid(),
             "Bad final plot bounds: {new_bounds:?}"
         );
 
+        // Only convert back the axes we had to change, so we don't accumulate rounding errors:
+        let mut final_bounds = bounds_in_data_space;
+        let converted = bounds_from_scaled(&new_bounds, &scales);
+        if new_bounds.range_x() != bounds.range_x() {
+            final_bounds.set_x(&converted);
+        }
+        if new_bounds.range_y() != bounds.range_y() {
+            final_bounds.set_y(&converted);
+        }
+
         Self {
             frame,
-            bounds: new_bounds,
+            bounds: final_bounds,
             centered: center_axis,
+            scales,
+            inverted: false.into(),
+            polar: None,
         }
     }
 
+    /// Reverse the direction of the x and/or y axis.
+    ///
+    /// Normally x grows to the right and y grows upwards.
+    #[inline]
+    pub fn with_inverted(mut self, inverted: impl Into<Vec2b>) -> Self {
+        self.inverted = inverted.into();
+        self
+    }
+
+    /// Whether the x axis grows to the left and/or the y axis grows downwards.
+    #[inline]
+    pub fn inverted(&self) -> Vec2b {
+        self.inverted
+    }
+
+    /// Treat data points as `(angle, radius)`.
+    ///
+    /// The bounds (and [`Self::position_from_point_x`]/[`Self::position_from_point_y`])
+    /// then refer to the cartesian plane the points are projected onto.
+    #[inline]
+    pub fn with_polar(mut self, polar: Option<Polar>) -> Self {
+        self.polar = polar;
+        self
+    }
+
+    /// The polar layout, if this is a polar plot.
+    #[inline]
+    pub fn polar(&self) -> Option<&Polar> {
+        self.polar.as_ref()
+    }
+
+    /// The same frame and scales, but with a y axis spanning the given range.
+    ///
+    /// This is how items on a secondary y axis are drawn.
+    pub(crate) fn with_y_range(&self, y_range: &RangeInclusive<f64>) -> Self {
+        let mut bounds = self.bounds;
+        bounds.min[1] = *y_range.start();
+        bounds.max[1] = *y_range.end();
+        Self::new_with_scales(self.frame, bounds, [self.centered.x, false], self.scales)
+            .with_inverted(self.inverted)
+    }
+
     /// ui-space rectangle.
     #[inline]
     pub fn frame(&self) -> &Rect {
@@ -358,6 +455,40 @@ impl PlotTransform {
         self.bounds = bounds;
     }
 
+    /// How values are mapped to the screen along the x and y axes.
+    #[inline]
+    pub fn scales(&self) -> &[AxisScale; 2] {
+        &self.scales
+    }
+
+    /// The bounds mapped through the axis scales.
+    ///
+    /// For linear axes this is the same as [`Self::bounds`].
+    #[inline]
+    pub fn scaled_bounds(&self) -> PlotBounds {
+        bounds_to_scaled(&self.bounds, &self.scales)
+    }
+
+    /// The visible range along the given axis (0 = x, 1 = y), mapped through its scale.
+    #[inline]
+    fn scaled_range(&self, axis: usize) -> RangeInclusive<f64> {
+        let scale = &self.scales[axis];
+        scale.forward(self.bounds.min[axis])..=scale.forward(self.bounds.max[axis])
+    }
+
+    /// Set the bounds from the scaled space, leaving any axis whose new range is outside of
+    /// the domain of its scale (e.g. when zooming out past zero on a reciprocal axis) as it was.
+    #[inline]
+    fn set_scaled_bounds(&mut self, scaled_bounds: &PlotBounds) {
+        let bounds = bounds_from_scaled(scaled_bounds, &self.scales);
+        for (axis, scale) in self.scales.iter().enumerate() {
+            if scale.is_valid_scaled_range(scaled_bounds.min[axis], scaled_bounds.max[axis]) {
+                self.bounds.min[axis] = bounds.min[axis];
+                self.bounds.max[axis] = bounds.max[axis];
+            }
+        }
+    }
+
     pub fn translate_bounds(&mut self, mut delta_pos: (f64, f64)) {
         if self.centered.x {
             delta_pos.0 = 0.;
@@ -365,41 +496,52 @@ impl PlotTransform {
         if self.centered.y {
             delta_pos.1 = 0.;
         }
-        delta_pos.0 *= self.dvalue_dpos()[0];
-        delta_pos.1 *= self.dvalue_dpos()[1];
-        self.bounds.translate((delta_pos.0, delta_pos.1));
+        let mut scaled_bounds = self.scaled_bounds();
+        delta_pos.0 *= self.dscaled_dpos()[0];
+        delta_pos.1 *= self.dscaled_dpos()[1];
+        scaled_bounds.translate((delta_pos.0, delta_pos.1));
+        self.set_scaled_bounds(&scaled_bounds);
     }
 
     /// Zoom by a relative factor with the given screen position as center.
     pub fn zoom(&mut self, zoom_factor: Vec2, center: Pos2) {
-        let center = self.value_from_position(center);
+        let center = self.scaled_value_from_position(center);
 
-        let mut new_bounds = self.bounds;
+        let mut new_bounds = self.scaled_bounds();
         new_bounds.zoom(zoom_factor, center);
 
         if new_bounds.is_valid() {
-            self.bounds = new_bounds;
+            self.set_scaled_bounds(&new_bounds);
         }
     }
 
     pub fn position_from_point_x(&self, value: f64) -> f32 {
+        let scaled_range = self.scaled_range(0);
         remap(
-            value,
-            self.bounds.min[0]..=self.bounds.max[0],
-            (self.frame.left() as f64)..=(self.frame.right() as f64),
+            finite_scaled_value(self.scales[0].forward(value), &scaled_range),
+            scaled_range,
+            self.screen_range_x(),
         ) as f32
     }
 
     pub fn position_from_point_y(&self, value: f64) -> f32 {
+        let scaled_range = self.scaled_range(1);
         remap(
-            value,
-            self.bounds.min[1]..=self.bounds.max[1],
-            (self.frame.bottom() as f64)..=(self.frame.top() as f64), // negated y axis!
+            finite_scaled_value(self.scales[1].forward(value), &scaled_range),
+            scaled_range,
+            self.screen_range_y(),
         ) as f32
     }
 
     /// Screen/ui position from point on plot.
     pub fn position_from_point(&self, value: &PlotPoint) -> Pos2 {
+        if let Some(polar) = &self.polar {
+            let cartesian = polar.cartesian_from_polar(value);
+            return pos2(
+                self.position_from_point_x(cartesian.x),
+                self.position_from_point_y(cartesian.y),
+            );
+        }
         pos2(
             self.position_from_point_x(value.x),
             self.position_from_point_y(value.y),
@@ -408,19 +550,67 @@ impl PlotTransform {
 
     /// Plot point from screen/ui position.
     pub fn value_from_position(&self, pos: Pos2) -> PlotPoint {
-        let x = remap(
-            pos.x as f64,
-            (self.frame.left() as f64)..=(self.frame.right() as f64),
-            self.bounds.range_x(),
-        );
-        let y = remap(
-            pos.y as f64,
-            (self.frame.bottom() as f64)..=(self.frame.top() as f64), // negated y axis!
-            self.bounds.range_y(),
+        let value = self.plane_value_from_position(pos);
+        match &self.polar {
+            Some(polar) => polar.polar_from_cartesian(&value),
+            None => value,
+        }
+    }
+
+    /// Position on the plane the bounds refer to from screen/ui position.
+    ///
+    /// This is the same as [`Self::value_from_position`], except in a polar plot,
+    /// where it is the cartesian position instead of `(angle, radius)`.
+    pub(crate) fn plane_value_from_position(&self, pos: Pos2) -> PlotPoint {
+        let scaled = self.scaled_value_from_position(pos);
+        PlotPoint::new(
+            self.scales[0].inverse(scaled.x),
+            self.scales[1].inverse(scaled.y),
+        )
+    }
+
+    /// The bounds of the screen rectangle with corners `pos1` and `pos2`, e.g. for a boxed zoom.
+    ///
+    /// In a polar plot, the bounds are on the cartesian plane, not `(angle, radius)`.
+    pub(crate) fn plane_bounds_from_positions(&self, pos1: Pos2, pos2: Pos2) -> PlotBounds {
+        let (value1, value2) = (
+            self.plane_value_from_position(pos1),
+            self.plane_value_from_position(pos2),
         );
+        PlotBounds {
+            min: [value1.x.min(value2.x), value1.y.min(value2.y)],
+            max: [value1.x.max(value2.x), value1.y.max(value2.y)],
+        }
+    }
+
+    /// Position in the scaled space (where both axes are linear) from screen/ui position.
+    fn scaled_value_from_position(&self, pos: Pos2) -> PlotPoint {
+        let scaled_bounds = self.scaled_bounds();
+        let x = remap(pos.x as f64, self.screen_range_x(), scaled_bounds.range_x());
+        let y = remap(pos.y as f64, self.screen_range_y(), scaled_bounds.range_y());
         PlotPoint::new(x, y)
     }
 
+    /// Screen positions of the minimum and maximum of the x bounds.
+    fn screen_range_x(&self) -> RangeInclusive<f64> {
+        let (left, right) = (self.frame.left() as f64, self.frame.right() as f64);
+        if self.inverted.x {
+            right..=left
+        } else {
+            left..=right
+        }
+    }
+
+    /// Screen positions of the minimum and maximum of the y bounds.
+    fn screen_range_y(&self) -> RangeInclusive<f64> {
+        let (top, bottom) = (self.frame.top() as f64, self.frame.bottom() as f64);
+        if self.inverted.y {
+            top..=bottom
+        } else {
+            bottom..=top // negated y axis!
+        }
+    }
+
     /// Transform a rectangle of plot values to a screen-coordinate rectangle.
     ///
     /// This typically means that the rect is mirrored vertically (top becomes bottom and vice versa),
@@ -436,13 +626,19 @@ impl PlotTransform {
     }
 
     /// delta position / delta value = how many ui points per step in the X axis in "plot space"
+    ///
+    /// For non-linear axis scales this is measured at the center of the visible range,
+    /// see [`Self::dpos_dvalue_at`].
     pub fn dpos_dvalue_x(&self) -> f64 {
-        self.frame.width() as f64 / self.bounds.width()
+        self.dpos_dvalue_at(&self.center_value())[0]
     }
 
     /// delta position / delta value = how many ui points per step in the Y axis in "plot space"
+    ///
+    /// For non-linear axis scales this is measured at the center of the visible range,
+    /// see [`Self::dpos_dvalue_at`].
     pub fn dpos_dvalue_y(&self) -> f64 {
-        -self.frame.height() as f64 / self.bounds.height() // negated y axis!
+        self.dpos_dvalue_at(&self.center_value())[1]
     }
 
     /// delta position / delta value = how many ui points per step in "plot space"
@@ -455,13 +651,73 @@ impl PlotTransform {
         [1.0 / self.dpos_dvalue_x(), 1.0 / self.dpos_dvalue_y()]
     }
 
+    /// Like [`Self::dpos_dvalue`], but measured at the given value.
+    ///
+    /// This only differs from [`Self::dpos_dvalue`] for non-linear axis scales.
+    pub fn dpos_dvalue_at(&self, value: &PlotPoint) -> [f64; 2] {
+        let dpos_dscaled = self.dpos_dscaled();
+        [
+            dpos_dscaled[0] * self.scales[0].derivative(value.x),
+            dpos_dscaled[1] * self.scales[1].derivative(value.y),
+        ]
+    }
+
+    /// Like [`Self::dvalue_dpos`], but measured at the given value.
+    ///
+    /// This only differs from [`Self::dvalue_dpos`] for non-linear axis scales.
+    pub fn dvalue_dpos_at(&self, value: &PlotPoint) -> [f64; 2] {
+        let dpos_dvalue = self.dpos_dvalue_at(value);
+        [1.0 / dpos_dvalue[0], 1.0 / dpos_dvalue[1]]
+    }
+
+    /// How many ui points there are between a grid mark and its neighbor of the same strength.
+    ///
+    /// On non-linear scales the neighbors on either side are at different distances;
+    /// the larger of the two is used.
+    pub fn spacing_in_points(&self, axis: Axis, mark: &GridMark) -> f32 {
+        let scale = &self.scales[usize::from(axis)];
+        let scaled_value = scale.forward(mark.value);
+        let scaled_distance = [mark.value - mark.step_size, mark.value + mark.step_size]
+            .into_iter()
+            .filter(|neighbor| scale.is_valid(*neighbor))
+            .map(|neighbor| (scaled_value - scale.forward(neighbor)).abs())
+            .fold(0.0, f64::max);
+        (scaled_distance * self.dpos_dscaled()[usize::from(axis)]).abs() as f32
+    }
+
+    /// The value at the center of the frame.
+    fn center_value(&self) -> PlotPoint {
+        let scaled_center = self.scaled_bounds().center();
+        PlotPoint::new(
+            self.scales[0].inverse(scaled_center.x),
+            self.scales[1].inverse(scaled_center.y),
+        )
+    }
+
+    /// How many ui points per step in the scaled space.
+    fn dpos_dscaled(&self) -> [f64; 2] {
+        let scaled_bounds = self.scaled_bounds();
+        let (screen_x, screen_y) = (self.screen_range_x(), self.screen_range_y());
+        [
+            (screen_x.end() - screen_x.start()) / scaled_bounds.width(),
+            (screen_y.end() - screen_y.start()) / scaled_bounds.height(),
+        ]
+    }
+
+    /// How much ground we cover in the scaled space per ui point.
+    fn dscaled_dpos(&self) -> [f64; 2] {
+        let dpos_dscaled = self.dpos_dscaled();
+        [1.0 / dpos_dscaled[0], 1.0 / dpos_dscaled[1]]
+    }
+
     /// scale.x/scale.y ratio.
     ///
     /// If 1.0, it means the scale factor is the same in both axes.
     fn aspect(&self) -> f64 {
         let rw = self.frame.width() as f64;
         let rh = self.frame.height() as f64;
-        (self.bounds.width() / rw) / (self.bounds.height() / rh)
+        let scaled_bounds = self.scaled_bounds();
+        (scaled_bounds.width() / rw) / (scaled_bounds.height() / rh)
     }
 
     /// Sets the aspect ratio by expanding the x- or y-axis.
@@ -476,13 +732,13 @@ impl PlotTransform {
             return;
         }
 
+        let mut scaled_bounds = self.scaled_bounds();
         if current_aspect < aspect {
-            self.bounds
-                .expand_x((aspect / current_aspect - 1.0) * self.bounds.width() * 0.5);
+            scaled_bounds.expand_x((aspect / current_aspect - 1.0) * scaled_bounds.width() * 0.5);
         } else {
-            self.bounds
-                .expand_y((current_aspect / aspect - 1.0) * self.bounds.height() * 0.5);
+            scaled_bounds.expand_y((current_aspect / aspect - 1.0) * scaled_bounds.height() * 0.5);
         }
+        self.set_scaled_bounds(&scaled_bounds);
     }
 
     /// Sets the aspect ratio by changing either the X or Y axis (callers choice).
@@ -495,15 +751,136 @@ impl PlotTransform {
             return;
         }
 
+        let mut scaled_bounds = self.scaled_bounds();
         match axis {
             Axis::X => {
-                self.bounds
-                    .expand_x((aspect / current_aspect - 1.0) * self.bounds.width() * 0.5);
+                scaled_bounds
+                    .expand_x((aspect / current_aspect - 1.0) * scaled_bounds.width() * 0.5);
             }
             Axis::Y => {
-                self.bounds
-                    .expand_y((current_aspect / aspect - 1.0) * self.bounds.height() * 0.5);
+                scaled_bounds
+                    .expand_y((current_aspect / aspect - 1.0) * scaled_bounds.height() * 0.5);
             }
         }
+        self.set_scaled_bounds(&scaled_bounds);
+    }
+}
+
+/// Map bounds in data units through the given axis scales.
+pub(crate) fn bounds_to_scaled(bounds: &PlotBounds, scales: &[AxisScale; 2]) -> PlotBounds {
+    if scales.iter().all(AxisScale::is_linear) {
+        return *bounds;
+    }
+    PlotBounds {
+        min: [
+            scales[0].forward(bounds.min[0]),
+            scales[1].forward(bounds.min[1]),
+        ],
+        max: [
+            scales[0].forward(bounds.max[0]),
+            scales[1].forward(bounds.max[1]),
+        ],
+    }
+}
+
+/// The inverse of [`bounds_to_scaled`].
+///
+/// The bounds are ordered even if a scaled range maps back reversed, e.g. when it is
+/// partly outside of the domain of its scale.
+pub(crate) fn bounds_from_scaled(
+    scaled_bounds: &PlotBounds,
+    scales: &[AxisScale; 2],
+) -> PlotBounds {
+    if scales.iter().all(AxisScale::is_linear) {
+        return *scaled_bounds;
+    }
+    let mut bounds = PlotBounds::NOTHING;
+    for (axis, scale) in scales.iter().enumerate() {
+        let (min, max) = (
+            scale.inverse(scaled_bounds.min[axis]),
+            scale.inverse(scaled_bounds.max[axis]),
+        );
+        bounds.min[axis] = min.min(max);
+        bounds.max[axis] = min.max(max);
+    }
+    bounds
+}
+
+/// Values outside the domain of a scale (e.g. zero on a log axis) end up far outside the visible range.
+#[inline]
+fn finite_scaled_value(scaled: f64, visible: &RangeInclusive<f64>) -> f64 {
+    if scaled.is_finite() || scaled.is_nan() {
+        scaled
+    } else {
+        let far = 1e3 * (visible.end() - visible.start());
+        if scaled < 0.0 {
+            visible.start() - far
+        } else {
+            visible.end() + far
+        }
+    }
+}
+
+#[test]
+fn test_inverted_axes() {
+    let frame = Rect::from_min_size(Pos2::ZERO, Vec2::new(200.0, 100.0));
+    let bounds = PlotBounds::from_min_max([0.0, -5.0], [10.0, 5.0]);
+    let close = |a: PlotPoint, b: PlotPoint| (a.x - b.x).abs() < 1e-6 && (a.y - b.y).abs() < 1e-6;
+
+    for inverted in [[false, false], [true, false], [false, true], [true, true]] {
+        let transform = PlotTransform::new(frame, bounds, false).with_inverted(inverted);
+
+        for point in [PlotPoint::new(2.5, -1.0), PlotPoint::new(7.0, 4.0)] {
+            let round_trip = transform.value_from_position(transform.position_from_point(&point));
+            assert!(close(round_trip, point), "{inverted:?}: {round_trip:?}");
+        }
+
+        // The smallest values are on the right and/or at the top when inverted:
+        let min = transform.position_from_point(&PlotPoint::new(0.0, -5.0));
+        let expected = pos2(
+            if inverted[0] { 200.0 } else { 0.0 },
+            if inverted[1] { 0.0 } else { 100.0 },
+        );
+        assert_eq!(min, expected, "{inverted:?}");
+
+        // Dragging (see `Plot::show`) keeps what was grabbed under the pointer:
+        let (grab, drag) = (pos2(50.0, 30.0), Vec2::new(20.0, -10.0));
+        let mut dragged = transform;
+        dragged.translate_bounds((-drag.x as f64, -drag.y as f64));
+        assert!(
+            close(
+                dragged.value_from_position(grab + drag),
+                transform.value_from_position(grab)
+            ),
+            "{inverted:?}"
+        );
+
+        // So does zooming around the pointer:
+        let mut zoomed = transform;
+        zoomed.zoom(Vec2::splat(2.0), grab);
+        assert!(
+            close(
+                zoomed.value_from_position(grab),
+                transform.value_from_position(grab)
+            ),
+            "{inverted:?}"
+        );
+
+        // A zoom box from (20, 10) to (120, 60) on screen:
+        let boxed = transform.plane_bounds_from_positions(pos2(120.0, 10.0), pos2(20.0, 60.0));
+        let (x_range, y_range) = (
+            if inverted[0] { 4.0..=9.0 } else { 1.0..=6.0 },
+            if inverted[1] { -4.0..=1.0 } else { -1.0..=4.0 },
+        );
+        assert!(
+            close(
+                PlotPoint::from(boxed.min()),
+                PlotPoint::new(*x_range.start(), *y_range.start())
+            ) && close(
+                PlotPoint::from(boxed.max()),
+                PlotPoint::new(*x_range.end(), *y_range.end())
+            ),
+            "{inverted:?}: {boxed:?}"
+        );
     }
 }